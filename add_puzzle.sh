@@ -86,6 +86,11 @@ fn main() {
     let input = match read_input().expect("failed to read input") {
         Outcome::Exit => return,
         Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
     };
 
     let parsed = parse_input(input);
@@ -181,6 +186,11 @@ fn main() {
     let input = match read_input().expect("failed to read input") {
         Outcome::Exit => return,
         Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
     };
 
     let parsed = parse_input(input);
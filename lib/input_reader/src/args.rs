@@ -1,63 +1,372 @@
 //! Command-line argument parsing for puzzle input handling.
 //!
-//! This module provides a fail-safe argument parser that prioritizes usability over strictness.
-//! Unknown flags and invalid values are treated as data rather than causing errors.
+//! This module provides an argument parser that prioritizes usability over strictness.
+//! Invalid values of optional flag values are treated as data rather than causing errors,
+//! but unknown flags are reported, as they're usually typos of the reader's own flags.
+//!
+//! Unknown long flags with a value, like `--seconds 2503` or `--liters=150`, are collected
+//! as pass-through [`Options`] for the solution instead, so solutions can take their own
+//! parameters without parsing the arguments themselves.
+
+use crate::environment::Environment;
+use crate::types::{InputMethod, OutputFormat};
+use std::{collections::HashMap, error, fmt, iter::Peekable, path::PathBuf, str::FromStr};
+
+/// A flag owned by the reader.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Flag {
+    Help,
+    Input,
+    Save,
+    Force,
+    Verbose,
+    Output,
+    InputPath,
+    DryRun,
+    Clipboard,
+    AllowEmpty,
+}
+
+/// Whether a flag takes a value, and what the value is called in the help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FlagValue {
+    /// The flag never takes a value.
+    None,
+    /// The flag takes the next argument only if it's a valid value.
+    Optional(&'static str),
+    /// The flag always takes the next argument.
+    Required(&'static str),
+}
+
+/// The description of a reader flag, used both to parse it and to print the help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FlagSpec {
+    /// Which flag this is, deciding what it sets.
+    pub flag: Flag,
+    /// The long name, without the leading `--`.
+    pub long: &'static str,
+    /// The short name, without the leading `-`.
+    pub short: Option<char>,
+    /// Whether the flag takes a value.
+    pub value: FlagValue,
+    /// The help text, with a line break before each extra paragraph.
+    pub description: &'static str,
+}
+
+/// The flags owned by the reader, in the order of the help.
+///
+/// Their long names are never pass-through options.
+pub(crate) const FLAGS: [FlagSpec; 10] = [
+    FlagSpec {
+        flag: Flag::Help,
+        long: "help",
+        short: Some('h'),
+        value: FlagValue::None,
+        description: "Print this help message",
+    },
+    FlagSpec {
+        flag: Flag::Input,
+        long: "input",
+        short: Some('i'),
+        value: FlagValue::Optional("METHOD"),
+        description: "Set input method (no value defaults to file)\nMethods: file, args, stdin",
+    },
+    FlagSpec {
+        flag: Flag::Save,
+        long: "save",
+        short: Some('s'),
+        value: FlagValue::None,
+        description: "Save input to file for future runs",
+    },
+    FlagSpec {
+        flag: Flag::Force,
+        long: "force",
+        short: Some('f'),
+        value: FlagValue::None,
+        description: "Force operations without prompts",
+    },
+    FlagSpec {
+        flag: Flag::Verbose,
+        long: "verbose",
+        short: Some('v'),
+        value: FlagValue::None,
+        description: "Report extra information, like the input file used",
+    },
+    FlagSpec {
+        flag: Flag::Output,
+        long: "output",
+        short: Some('o'),
+        value: FlagValue::Optional("FORMAT"),
+        description: "Set the notification format (no value defaults to text)\nFormats: text, json",
+    },
+    FlagSpec {
+        flag: Flag::InputPath,
+        long: "input-path",
+        short: None,
+        value: FlagValue::Required("PATH"),
+        description: "Read and save input at PATH instead of searching",
+    },
+    FlagSpec {
+        flag: Flag::DryRun,
+        long: "dry-run",
+        short: None,
+        value: FlagValue::None,
+        description: "Load (and save) the input without solving it",
+    },
+    FlagSpec {
+        flag: Flag::Clipboard,
+        long: "clipboard",
+        short: None,
+        value: FlagValue::None,
+        description: "Read input from the clipboard (needs the clipboard feature)",
+    },
+    FlagSpec {
+        flag: Flag::AllowEmpty,
+        long: "allow-empty",
+        short: None,
+        value: FlagValue::None,
+        description: "Use the input file even if it's empty or only whitespace",
+    },
+];
+
+/// Finds the reader flag with the long name `long`.
+fn find_long(long: &str) -> Option<&'static FlagSpec> {
+    FLAGS.iter().find(|spec| spec.long == long)
+}
+
+/// Finds the reader flag with the short name `short`.
+fn find_short(short: char) -> Option<&'static FlagSpec> {
+    FLAGS.iter().find(|spec| spec.short == Some(short))
+}
+
+/// Suggests the reader flag closest to the unknown long flag `flag`.
+///
+/// A flag that starts like a reader flag is taken to be an abbreviation of it, otherwise
+/// the reader flag within two edits is suggested. Short flags get no suggestion.
+fn suggest_flag(flag: &str) -> Option<&'static str> {
+    let name = flag.strip_prefix("--")?;
+    let name = name.split_once('=').map_or(name, |(name, _)| name);
+    if name.len() < 2 {
+        return None;
+    }
+
+    FLAGS
+        .iter()
+        .find(|spec| spec.long.starts_with(name))
+        .or_else(|| {
+            FLAGS
+                .iter()
+                .map(|spec| (edit_distance(name, spec.long), spec))
+                .filter(|&(distance, _)| distance <= 2)
+                .min_by_key(|&(distance, _)| distance)
+                .map(|(_, spec)| spec)
+        })
+        .map(|spec| spec.long)
+}
 
-use crate::types::InputMethod;
-use std::env;
+/// The Levenshtein distance between `a` and `b`, counting characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = Vec::with_capacity(previous.len());
+        let mut left = i.saturating_add(1);
+        current.push(left);
+
+        for ((&b_char, &diagonal), &above) in b.iter().zip(&previous).zip(previous.iter().skip(1)) {
+            left = diagonal
+                .saturating_add(usize::from(a_char != b_char))
+                .min(above.saturating_add(1))
+                .min(left.saturating_add(1));
+            current.push(left);
+        }
+
+        previous = current;
+    }
+
+    previous.last().copied().unwrap_or_default()
+}
 
 /// Parsed command-line arguments.
 ///
 /// Contains the flags and data extracted from command-line arguments.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, PartialEq)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "each bool is an independent command-line flag"
+)]
 pub(crate) struct ParsedArgs {
     /// Whether help was requested (`--help` or `-h`).
     pub help: bool,
-    /// The input method to use (`--input` or `-i`).
+    /// The input method to use (`--input`, `-i` or `--clipboard`).
     pub input: InputMethod,
     /// Whether to save the input (`--save` or `-s`).
     pub save: bool,
     /// Whether to force operations without prompts (`--force` or `-f`).
     pub force: bool,
+    /// Whether to report extra information, like the input file used (`--verbose` or `-v`).
+    pub verbose: bool,
+    /// An explicit input file path overriding the search (`--input-path`).
+    pub input_path: Option<PathBuf>,
+    /// The format of notifications (`--output` or `-o`).
+    pub output: OutputFormat,
+    /// Whether to only load (and save) the input without solving it (`--dry-run`).
+    pub dry_run: bool,
+    /// Whether an input file without any content is still used (`--allow-empty`).
+    pub allow_empty: bool,
+    /// Unrecognized `--key value` and `--key=value` pairs, passed through to the solution.
+    pub options: Options,
     /// Positional arguments and unrecognized flags treated as data.
     pub data: Vec<String>,
 }
 
-/// Parses command-line arguments from the environment.
+/// Solution-specific options passed on the command line.
 ///
-/// This is a fail-safe parser that treats unknown flags and invalid values as data.
+/// These are the `--key value` and `--key=value` pairs the reader doesn't recognize
+/// itself. When a key is given more than once, the last value is kept.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Options {
+    values: HashMap<String, String>,
+}
+
+impl Options {
+    /// Gets the raw value of the option `key`, without the leading `--`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Gets the value of the option `key` parsed as `T`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(value))` - The option was given and parsed
+    /// * `Ok(None)` - The option wasn't given
+    /// * `Err(ArgError::InvalidValue)` - The option was given, but couldn't be parsed
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArgError::InvalidValue`] naming the key if the value can't be parsed.
+    pub fn get_arg<T: FromStr>(&self, key: &str) -> Result<Option<T>, ArgError>
+    where
+        T::Err: fmt::Display,
+    {
+        self.get(key)
+            .map(|value| {
+                value.parse().map_err(|err: T::Err| ArgError::InvalidValue {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    message: err.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Sets the option `key`, replacing any earlier value.
+    fn insert(&mut self, key: String, value: String) {
+        self.values.insert(key, value);
+    }
+}
+
+/// Errors about command-line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgError {
+    /// A flag isn't one of the reader's flags, and has no value to be an option.
+    UnknownFlag(String),
+    /// A flag that requires a value was given without one.
+    MissingValue(String),
+    /// Two flags were given that can't be used together, in the order they were given.
+    ConflictingFlags(String, String),
+    /// The value of a pass-through option could not be parsed.
+    InvalidValue {
+        /// The key of the option, without the leading `--`.
+        key: String,
+        /// The value as it was given.
+        value: String,
+        /// The message of the underlying parse error.
+        message: String,
+    },
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgError::UnknownFlag(flag) => match suggest_flag(flag) {
+                Some(suggestion) => write!(f, "unknown flag {flag} (did you mean --{suggestion}?)"),
+                None => write!(f, "unknown flag {flag} (use -- to pass it as data)"),
+            },
+            ArgError::MissingValue(flag) => {
+                let value_name = flag
+                    .strip_prefix("--")
+                    .and_then(find_long)
+                    .or_else(|| flag.strip_prefix('-')?.chars().next().and_then(find_short))
+                    .and_then(|spec| match spec.value {
+                        FlagValue::Required(name) | FlagValue::Optional(name) => Some(name),
+                        FlagValue::None => None,
+                    });
+                match value_name {
+                    Some(name) => write!(f, "missing value for {flag} (expected {name})"),
+                    None => write!(f, "missing value for {flag}"),
+                }
+            }
+            ArgError::ConflictingFlags(first, second) => {
+                write!(f, "conflicting flags {first} and {second}")
+            }
+            ArgError::InvalidValue {
+                key,
+                value,
+                message,
+            } => write!(f, "invalid value {value:?} for --{key}: {message}"),
+        }
+    }
+}
+
+impl error::Error for ArgError {}
+
+/// Parses the command-line arguments of `env`.
+///
+/// Invalid values of flags with optional values are treated as data, unknown flags are
+/// errors.
 ///
 /// # Supported flags
 ///
-/// - `--help`, `-h`: Request help
-/// - `--input [method]`, `-i [method]`: Set input method
-///   - Valid values: `file`, `args`, `stdin`
-///   - Value is optional; if omitted or invalid, defaults to `file`
-/// - `--save`, `-s`: Enable save mode
-/// - `--force`, `-f`: Force operations without prompts
+/// - The reader flags in [`FLAGS`], like `--save` or `-s`
+/// - `--input [method]`, `-i [method]`: Valid values are `file`, `args` and `stdin`
+/// - `--output [format]`, `-o [format]`: Valid values are `text` and `json`
+/// - `--key value`, `--key=value`: Pass an option through to the solution
+///   - The space form only takes a value that doesn't start with `-`, unless it's a
+///     negative number like `-5`
+///   - The space form is refused for keys that look like a mistyped reader flag without
+///     a value, like `--sav line1`, so the value isn't lost from the data. Numbers are
+///     still taken as values, so `--size 10` is an option.
 /// - `--`: Stop parsing flags, treat everything after as data
 ///
 /// # Behavior
 ///
 /// - Repeated flags overwrite previous values (no errors)
-/// - Unknown flags are added to data
-/// - Invalid input method values default to `File` and the value becomes data
+/// - Different flags choosing the input method, like `--clipboard` and `--input`, conflict
+/// - Reader flags are never options, even in the `=` form
+/// - Invalid optional values are left as data, and the flag gets its default value
 /// - Short flags can be grouped (e.g., `-isf` for input, save, and force)
 /// - Only the last flag in a group can take a value (e.g., `-sfi stdin`)
-pub(crate) fn parse_args() -> ParsedArgs {
-    parse_args_from(env::args().skip(1))
+/// - A `-` followed by a digit is a negative number, which is data
+///
+/// # Errors
+///
+/// - [`ArgError::UnknownFlag`] for other unknown flags, including `=` forms of reader flags
+///   and space forms of keys that look like a mistyped reader flag
+/// - [`ArgError::MissingValue`] if a flag with a required value has none
+/// - [`ArgError::ConflictingFlags`] if different flags choose the input method
+pub(crate) fn parse_args(env: &dyn Environment) -> Result<ParsedArgs, ArgError> {
+    parse_args_from(env.args().into_iter())
 }
 
 /// Parses arguments from an iterator.
-fn parse_args_from(args: impl Iterator<Item = String>) -> ParsedArgs {
-    let mut help = false;
-    let mut input = InputMethod::Auto;
-    let mut save = false;
-    let mut force = false;
-    let mut data = Vec::new();
-
+fn parse_args_from(args: impl Iterator<Item = String>) -> Result<ParsedArgs, ArgError> {
+    let mut parsed = ParsedArgs::default();
     let mut args_iter = args.peekable();
     let mut parse_flags = true;
+    let mut input_flag = None;
 
     while let Some(arg) = args_iter.next() {
         let arg = arg.trim();
@@ -68,58 +377,116 @@ fn parse_args_from(args: impl Iterator<Item = String>) -> ParsedArgs {
         }
 
         if !parse_flags {
-            data.push(arg.to_string());
+            parsed.data.push(arg.to_string());
             continue;
         }
 
-        if arg.starts_with("--") && arg.len() > 2 {
+        if let Some(long) = arg.strip_prefix("--").filter(|long| !long.is_empty()) {
             // Individual flags
-            match arg {
-                "--help" | "-h" => help = true,
-                "--input" | "-i" => {
-                    input = parse_input_value(&mut args_iter);
-                }
-                "--save" | "-s" => save = true,
-                "--force" | "-f" => force = true,
-                _ => data.push(arg.to_string()),
+            if let Some(spec) = find_long(long) {
+                check_input_conflict(spec, arg, &mut input_flag)?;
+                apply_flag(spec, arg, true, &mut args_iter, &mut parsed)?;
+            } else {
+                let (key, value) = parse_option(arg, &mut args_iter)
+                    .ok_or_else(|| ArgError::UnknownFlag(arg.to_string()))?;
+                parsed.options.insert(key, value);
             }
-        } else if arg.starts_with('-') && arg.len() > 1 {
+        } else if arg.starts_with('-') && arg.len() > 1 && !is_negative_number(arg) {
             // Grouped short flags
-            for (pos, flag) in arg[1..].chars().enumerate() {
-                #[expect(
-                    clippy::arithmetic_side_effects,
-                    reason = "we already checked the length"
-                )]
-                let is_last_flag = pos == arg.len() - 2;
-
-                match flag {
-                    'h' => help = true,
-                    'i' => {
-                        if is_last_flag {
-                            input = parse_input_value(&mut args_iter);
-                        } else {
-                            input = InputMethod::File;
-                        }
-                    }
-                    's' => save = true,
-                    'f' => force = true,
-                    _ => data.push(arg.to_string()),
-                }
+            let mut shorts = arg.chars().skip(1).peekable();
+
+            while let Some(short) = shorts.next() {
+                let flag = format!("-{short}");
+                let spec = find_short(short).ok_or_else(|| ArgError::UnknownFlag(flag.clone()))?;
+                let is_last_flag = shorts.peek().is_none();
+                check_input_conflict(spec, &flag, &mut input_flag)?;
+                apply_flag(spec, &flag, is_last_flag, &mut args_iter, &mut parsed)?;
             }
         } else {
-            data.push(arg.to_string());
+            parsed.data.push(arg.to_string());
         }
     }
 
-    ParsedArgs {
-        help,
-        input,
-        save,
-        force,
-        data,
+    Ok(parsed)
+}
+
+/// Checks that the reader flag `spec`, given as `arg`, doesn't choose a different input
+/// method than an earlier flag.
+///
+/// `input_flag` is the earlier flag choosing the input method and how it was given, and
+/// is updated when `spec` chooses the input method.
+fn check_input_conflict(
+    spec: &FlagSpec,
+    arg: &str,
+    input_flag: &mut Option<(Flag, String)>,
+) -> Result<(), ArgError> {
+    if !matches!(spec.flag, Flag::Input | Flag::Clipboard) {
+        return Ok(());
+    }
+
+    match input_flag {
+        Some((flag, earlier)) if *flag != spec.flag => {
+            Err(ArgError::ConflictingFlags(earlier.clone(), arg.to_string()))
+        }
+        _ => {
+            *input_flag = Some((spec.flag, arg.to_string()));
+            Ok(())
+        }
     }
 }
 
+/// Applies the reader flag `spec`, given as `arg`, to `parsed`.
+///
+/// The flag's value is taken from `args_iter`, unless `can_take_value` is false because
+/// the flag isn't the last in a group.
+fn apply_flag(
+    spec: &FlagSpec,
+    arg: &str,
+    can_take_value: bool,
+    args_iter: &mut Peekable<impl Iterator<Item = String>>,
+    parsed: &mut ParsedArgs,
+) -> Result<(), ArgError> {
+    let mut required_value = || {
+        can_take_value
+            .then(|| args_iter.next())
+            .flatten()
+            .ok_or_else(|| ArgError::MissingValue(arg.to_string()))
+    };
+
+    match spec.flag {
+        Flag::Help => parsed.help = true,
+        Flag::Input => {
+            parsed.input = if can_take_value {
+                parse_input_value(args_iter)
+            } else {
+                InputMethod::File
+            };
+        }
+        Flag::Save => parsed.save = true,
+        Flag::Force => parsed.force = true,
+        Flag::Verbose => parsed.verbose = true,
+        Flag::Output => {
+            parsed.output = if can_take_value {
+                parse_output_value(args_iter)
+            } else {
+                OutputFormat::Text
+            };
+        }
+        Flag::InputPath => parsed.input_path = Some(PathBuf::from(required_value()?)),
+        Flag::DryRun => parsed.dry_run = true,
+        Flag::Clipboard => parsed.input = InputMethod::Clipboard,
+        Flag::AllowEmpty => parsed.allow_empty = true,
+    }
+
+    Ok(())
+}
+
+/// Whether `arg` is a negative number, like `-5`, rather than a group of short flags.
+fn is_negative_number(arg: &str) -> bool {
+    arg.strip_prefix('-')
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+}
+
 /// Parses the input method value from the next argument.
 ///
 /// Peeks at the next argument and returns the corresponding `InputMethod`.
@@ -148,7 +515,86 @@ fn parse_input_value(
     }
 }
 
+/// Parses the output format value from the next argument.
+///
+/// Works like [`parse_input_value`]: only a valid format is consumed, and invalid or
+/// missing values default to `Text`.
+fn parse_output_value(
+    args_iter: &mut std::iter::Peekable<impl Iterator<Item = String>>,
+) -> OutputFormat {
+    match args_iter.peek().map(String::as_str) {
+        Some("text") => {
+            args_iter.next();
+            OutputFormat::Text
+        }
+        Some("json") => {
+            args_iter.next();
+            OutputFormat::Json
+        }
+        _ => OutputFormat::Text,
+    }
+}
+
+/// Parses a pass-through option from an unrecognized long flag.
+///
+/// Takes the value after `=`, or else the next argument unless it looks like a flag. A
+/// negative number, like `-5`, is taken as a value.
+/// Returns `None` if the flag has no value or belongs to the reader, or if it's in the
+/// space form and looks like a mistyped reader flag, see [`is_mistyped_switch`].
+fn parse_option(
+    arg: &str,
+    args_iter: &mut Peekable<impl Iterator<Item = String>>,
+) -> Option<(String, String)> {
+    let flag = arg.strip_prefix("--")?;
+
+    if let Some((key, value)) = flag.split_once('=') {
+        if key.is_empty() || find_long(key).is_some() {
+            return None;
+        }
+        return Some((key.to_string(), value.to_string()));
+    }
+
+    if is_mistyped_switch(flag, args_iter.peek().map(String::as_str)) {
+        return None;
+    }
+
+    if args_iter
+        .peek()
+        .map(|value| value.trim())
+        .is_none_or(|value| value.starts_with('-') && !is_negative_number(value))
+    {
+        return None;
+    }
+
+    let value = args_iter.next()?;
+    Some((flag.to_string(), value.trim().to_string()))
+}
+
+/// Whether the unknown long flag `name`, followed by the argument `next`, is a typo of a
+/// reader flag that takes no value, like `--sav line1` for `--save`.
+///
+/// The name has to be an abbreviation of the flag or one edit away from it, and `next`
+/// must not look like a value, so options like `--size 10` or `--hp 50` aren't refused.
+fn is_mistyped_switch(name: &str, next: Option<&str>) -> bool {
+    if name.len() < 2 || next.is_some_and(looks_like_value) {
+        return false;
+    }
+
+    FLAGS
+        .iter()
+        .filter(|spec| spec.value == FlagValue::None)
+        .any(|spec| spec.long.starts_with(name) || edit_distance(name, spec.long) <= 1)
+}
+
+/// Whether `arg` looks like the value of an option rather than data, like `10` or `-2.5`.
+fn looks_like_value(arg: &str) -> bool {
+    let number = arg.trim().strip_prefix('-').unwrap_or(arg.trim());
+    number.starts_with(|c: char| c.is_ascii_digit())
+        && number.chars().all(|c| c.is_ascii_digit() || c == '.')
+}
+
 #[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
 mod tests {
     use super::*;
     use std::string::ToString;
@@ -162,9 +608,14 @@ mod tests {
             .into_iter()
     }
 
+    /// Helper to parse args that are expected to be valid
+    fn parse(items: &[&str]) -> ParsedArgs {
+        parse_args_from(args(items)).unwrap()
+    }
+
     #[test]
     fn empty_args() {
-        let result = parse_args_from(args(&[]));
+        let result = parse(&[]);
         assert_eq!(
             result,
             ParsedArgs {
@@ -172,6 +623,12 @@ mod tests {
                 input: InputMethod::Auto,
                 save: false,
                 force: false,
+                verbose: false,
+                input_path: None,
+                output: OutputFormat::Text,
+                dry_run: false,
+                allow_empty: false,
+                options: Options::default(),
                 data: vec![],
             }
         );
@@ -179,62 +636,62 @@ mod tests {
 
     #[test]
     fn help_long_flag() {
-        let result = parse_args_from(args(&["--help"]));
+        let result = parse(&["--help"]);
         assert!(result.help);
     }
 
     #[test]
     fn help_short_flag() {
-        let result = parse_args_from(args(&["-h"]));
+        let result = parse(&["-h"]);
         assert!(result.help);
     }
 
     #[test]
     fn input_file_long() {
-        let result = parse_args_from(args(&["--input", "file"]));
+        let result = parse(&["--input", "file"]);
         assert_eq!(result.input, InputMethod::File);
     }
 
     #[test]
     fn input_args_long() {
-        let result = parse_args_from(args(&["--input", "args"]));
+        let result = parse(&["--input", "args"]);
         assert_eq!(result.input, InputMethod::Args);
     }
 
     #[test]
     fn input_stdin_long() {
-        let result = parse_args_from(args(&["--input", "stdin"]));
+        let result = parse(&["--input", "stdin"]);
         assert_eq!(result.input, InputMethod::Stdin);
     }
 
     #[test]
     fn input_short_flag() {
-        let result = parse_args_from(args(&["-i", "args"]));
+        let result = parse(&["-i", "args"]);
         assert_eq!(result.input, InputMethod::Args);
     }
 
     #[test]
     fn save_long_flag() {
-        let result = parse_args_from(args(&["--save"]));
+        let result = parse(&["--save"]);
         assert!(result.save);
     }
 
     #[test]
     fn save_short_flag() {
-        let result = parse_args_from(args(&["-s"]));
+        let result = parse(&["-s"]);
         assert!(result.save);
     }
 
     #[test]
     fn grouped_short_flags() {
-        let result = parse_args_from(args(&["-hs"]));
+        let result = parse(&["-hs"]);
         assert!(result.help);
         assert!(result.save);
     }
 
     #[test]
     fn grouped_flags_with_input_last() {
-        let result = parse_args_from(args(&["-hsi", "stdin"]));
+        let result = parse(&["-hsi", "stdin"]);
         assert!(result.help);
         assert!(result.save);
         assert_eq!(result.input, InputMethod::Stdin);
@@ -242,7 +699,7 @@ mod tests {
 
     #[test]
     fn grouped_flags_input_not_last_defaults_to_file() {
-        let result = parse_args_from(args(&["-his"]));
+        let result = parse(&["-his"]);
         assert!(result.help);
         assert!(result.save);
         assert_eq!(result.input, InputMethod::File);
@@ -250,32 +707,51 @@ mod tests {
 
     #[test]
     fn invalid_input_value_becomes_data() {
-        let result = parse_args_from(args(&["--input", "foo"]));
+        let result = parse(&["--input", "foo"]);
         assert_eq!(result.input, InputMethod::File);
         assert_eq!(result.data, vec!["foo"]);
     }
 
     #[test]
     fn missing_input_value_defaults_to_file() {
-        let result = parse_args_from(args(&["--input"]));
+        let result = parse(&["--input"]);
         assert_eq!(result.input, InputMethod::File);
     }
 
     #[test]
-    fn unknown_long_flag_becomes_data() {
+    fn unknown_long_flag_is_an_error() {
         let result = parse_args_from(args(&["--unknown"]));
-        assert_eq!(result.data, vec!["--unknown"]);
+        assert_eq!(result, Err(ArgError::UnknownFlag("--unknown".to_string())));
+    }
+
+    #[test]
+    fn unknown_short_flag_is_an_error() {
+        let result = parse_args_from(args(&["-sx"]));
+        assert_eq!(result, Err(ArgError::UnknownFlag("-x".to_string())));
+    }
+
+    #[test]
+    fn unknown_flag_after_double_dash_is_data() {
+        let result = parse(&["--", "--unknown", "-x"]);
+        assert_eq!(result.data, vec!["--unknown", "-x"]);
+    }
+
+    #[test]
+    fn negative_numbers_are_data() {
+        let result = parse(&["-s", "-5", "-12", "-"]);
+        assert!(result.save);
+        assert_eq!(result.data, vec!["-5", "-12", "-"]);
     }
 
     #[test]
     fn positional_args_become_data() {
-        let result = parse_args_from(args(&["foo", "bar"]));
+        let result = parse(&["foo", "bar"]);
         assert_eq!(result.data, vec!["foo", "bar"]);
     }
 
     #[test]
     fn double_dash_separator() {
-        let result = parse_args_from(args(&["--", "--help", "-s"]));
+        let result = parse(&["--", "--help", "-s"]);
         assert!(!result.help);
         assert!(!result.save);
         assert_eq!(result.data, vec!["--help", "-s"]);
@@ -283,13 +759,41 @@ mod tests {
 
     #[test]
     fn repeated_flags_overwrite() {
-        let result = parse_args_from(args(&["--input", "file", "--input", "stdin"]));
+        let result = parse(&["--input", "file", "--input", "stdin"]);
         assert_eq!(result.input, InputMethod::Stdin);
+
+        let result = parse(&["-i", "file", "--input", "args"]);
+        assert_eq!(result.input, InputMethod::Args);
+    }
+
+    #[test]
+    fn different_input_method_flags_conflict() {
+        let result = parse_args_from(args(&["--clipboard", "--input", "args"]));
+        assert_eq!(
+            result,
+            Err(ArgError::ConflictingFlags(
+                "--clipboard".to_string(),
+                "--input".to_string()
+            ))
+        );
+
+        let result = parse_args_from(args(&["-si", "stdin", "--clipboard"]));
+        assert_eq!(
+            result,
+            Err(ArgError::ConflictingFlags(
+                "-i".to_string(),
+                "--clipboard".to_string()
+            ))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "conflicting flags -i and --clipboard"
+        );
     }
 
     #[test]
     fn mixed_flags_and_data() {
-        let result = parse_args_from(args(&["-hs", "--input", "args", "data1", "data2"]));
+        let result = parse(&["-hs", "--input", "args", "data1", "data2"]);
         assert!(result.help);
         assert!(result.save);
         assert_eq!(result.input, InputMethod::Args);
@@ -298,14 +802,14 @@ mod tests {
 
     #[test]
     fn flags_after_positional_args() {
-        let result = parse_args_from(args(&["data", "--help"]));
+        let result = parse(&["data", "--help"]);
         assert!(result.help);
         assert_eq!(result.data, vec!["data"]);
     }
 
     #[test]
     fn data_after_double_dash_includes_flags() {
-        let result = parse_args_from(args(&["--save", "--", "--input", "file"]));
+        let result = parse(&["--save", "--", "--input", "file"]);
         assert!(result.save);
         assert_eq!(result.input, InputMethod::Auto);
         assert_eq!(result.data, vec!["--input", "file"]);
@@ -313,19 +817,19 @@ mod tests {
 
     #[test]
     fn force_long_flag() {
-        let result = parse_args_from(args(&["--force"]));
+        let result = parse(&["--force"]);
         assert!(result.force);
     }
 
     #[test]
     fn force_short_flag() {
-        let result = parse_args_from(args(&["-f"]));
+        let result = parse(&["-f"]);
         assert!(result.force);
     }
 
     #[test]
     fn grouped_flags_with_force() {
-        let result = parse_args_from(args(&["-hsf"]));
+        let result = parse(&["-hsf"]);
         assert!(result.help);
         assert!(result.save);
         assert!(result.force);
@@ -333,9 +837,277 @@ mod tests {
 
     #[test]
     fn grouped_flags_with_force_and_input() {
-        let result = parse_args_from(args(&["-sfi", "stdin"]));
+        let result = parse(&["-sfi", "stdin"]);
         assert!(result.save);
         assert!(result.force);
         assert_eq!(result.input, InputMethod::Stdin);
     }
+
+    #[test]
+    fn verbose_long_flag() {
+        let result = parse(&["--verbose"]);
+        assert!(result.verbose);
+    }
+
+    #[test]
+    fn verbose_short_flag_grouped() {
+        let result = parse(&["-sv"]);
+        assert!(result.save);
+        assert!(result.verbose);
+    }
+
+    #[test]
+    fn input_path_flag() {
+        let result = parse(&["--input-path", "some/input.txt", "data"]);
+        assert_eq!(result.input_path, Some(PathBuf::from("some/input.txt")));
+        assert_eq!(result.data, vec!["data"]);
+    }
+
+    #[test]
+    fn missing_input_path_value_is_an_error() {
+        let result = parse_args_from(args(&["--save", "--input-path"]));
+        assert_eq!(
+            result,
+            Err(ArgError::MissingValue("--input-path".to_string()))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "missing value for --input-path (expected PATH)"
+        );
+    }
+
+    #[test]
+    fn output_json_long() {
+        let result = parse(&["--output", "json"]);
+        assert_eq!(result.output, OutputFormat::Json);
+    }
+
+    #[test]
+    fn output_short_flag_grouped() {
+        let result = parse(&["-so", "json"]);
+        assert!(result.save);
+        assert_eq!(result.output, OutputFormat::Json);
+    }
+
+    #[test]
+    fn invalid_output_value_becomes_data() {
+        let result = parse(&["--output", "yaml"]);
+        assert_eq!(result.output, OutputFormat::Text);
+        assert_eq!(result.data, vec!["yaml"]);
+    }
+
+    #[test]
+    fn dry_run_flag() {
+        let result = parse(&["--dry-run", "-s", "data"]);
+        assert!(result.dry_run);
+        assert!(result.save);
+        assert_eq!(result.data, vec!["data"]);
+    }
+
+    #[test]
+    fn allow_empty_flag() {
+        let result = parse(&["--allow-empty", "-i", "file"]);
+        assert!(result.allow_empty);
+        assert_eq!(result.input, InputMethod::File);
+        assert!(result.options.get("allow-empty").is_none());
+    }
+
+    #[test]
+    fn clipboard_flag_sets_input_method() {
+        let result = parse(&["--clipboard", "-s"]);
+        assert_eq!(result.input, InputMethod::Clipboard);
+        assert!(result.save);
+    }
+
+    #[test]
+    fn options_mixed_with_reader_flags() {
+        let result = parse(&[
+            "-s",
+            "--seconds",
+            "2503",
+            "--input",
+            "stdin",
+            "--liters=150",
+            "-v",
+        ]);
+        assert!(result.save);
+        assert!(result.verbose);
+        assert_eq!(result.input, InputMethod::Stdin);
+        assert_eq!(result.options.get("seconds"), Some("2503"));
+        assert_eq!(result.options.get("liters"), Some("150"));
+        assert!(result.data.is_empty());
+    }
+
+    #[test]
+    fn option_equals_form_allows_dashes_in_value() {
+        let result = parse(&["--offset=-5", "--empty="]);
+        assert_eq!(result.options.get("offset"), Some("-5"));
+        assert_eq!(result.options.get("empty"), Some(""));
+    }
+
+    #[test]
+    fn option_space_form_does_not_take_flags() {
+        let result = parse_args_from(args(&["--part", "--save"]));
+        assert_eq!(result, Err(ArgError::UnknownFlag("--part".to_string())));
+
+        let result = parse_args_from(args(&["--offset", "-x"]));
+        assert_eq!(result, Err(ArgError::UnknownFlag("--offset".to_string())));
+    }
+
+    #[test]
+    fn option_space_form_takes_negative_numbers() {
+        let result = parse(&["--offset", "-5", "--scale", " -0.5 ", "-3"]);
+        assert_eq!(result.options.get("offset"), Some("-5"));
+        assert_eq!(result.options.get("scale"), Some("-0.5"));
+        assert_eq!(result.data, vec!["-3"]);
+    }
+
+    #[test]
+    fn mistyped_reader_flag_does_not_take_a_value() {
+        let result = parse_args_from(args(&["--sav", "line1"]));
+        assert_eq!(result, Err(ArgError::UnknownFlag("--sav".to_string())));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unknown flag --sav (did you mean --save?)"
+        );
+
+        // The `=` form is still an option
+        let result = parse(&["--sav=line1"]);
+        assert_eq!(result.options.get("sav"), Some("line1"));
+    }
+
+    #[test]
+    fn options_close_to_reader_flags_take_values() {
+        let result = parse(&["--size", "10", "--hp", "50", "--in", "3", "--forc", "2"]);
+        assert_eq!(result.options.get("size"), Some("10"));
+        assert_eq!(result.options.get("hp"), Some("50"));
+        assert_eq!(result.options.get("in"), Some("3"));
+        assert_eq!(result.options.get("forc"), Some("2"));
+        assert!(result.data.is_empty());
+    }
+
+    #[test]
+    fn duplicate_option_keeps_last_value() {
+        let result = parse(&["--part", "1", "--part=2"]);
+        assert_eq!(result.options.get("part"), Some("2"));
+    }
+
+    #[test]
+    fn reader_flags_are_never_options() {
+        let result = parse_args_from(args(&["--input=stdin"]));
+        assert_eq!(
+            result,
+            Err(ArgError::UnknownFlag("--input=stdin".to_string()))
+        );
+    }
+
+    #[test]
+    fn options_and_positional_data() {
+        let result = parse(&["data1", "--part", "2", "data2", "--", "--x", "1"]);
+        assert_eq!(result.options.get("part"), Some("2"));
+        assert_eq!(result.options.get("x"), None);
+        assert_eq!(result.data, vec!["data1", "data2", "--x", "1"]);
+    }
+
+    #[test]
+    fn get_arg_parses_typed_values() {
+        let result = parse(&["--seconds", "2503"]);
+        assert_eq!(result.options.get_arg::<u32>("seconds"), Ok(Some(2503)));
+        assert_eq!(result.options.get_arg::<u32>("liters"), Ok(None));
+    }
+
+    #[test]
+    fn get_arg_parse_failure_names_the_key() {
+        let result = parse(&["--liters=lots"]);
+        let Err(err) = result.options.get_arg::<u32>("liters") else {
+            panic!("expected a parse failure");
+        };
+
+        assert!(matches!(
+            &err,
+            ArgError::InvalidValue { key, value, .. } if key == "liters" && value == "lots"
+        ));
+        assert!(
+            err.to_string()
+                .starts_with("invalid value \"lots\" for --liters: ")
+        );
+    }
+
+    #[test]
+    fn every_registered_flag_parses() {
+        for spec in &FLAGS {
+            // A value that differs from the default, for the flags that take one
+            let value = match spec.flag {
+                Flag::Input => Some("stdin"),
+                Flag::Output => Some("json"),
+                Flag::InputPath => Some("input.txt"),
+                _ => None,
+            };
+            let parse_with_value = |flag: &str| {
+                let mut items = vec![flag];
+                items.extend(value);
+                parse(&items)
+            };
+
+            let long = format!("--{}", spec.long);
+            let result = parse_with_value(&long);
+            assert_ne!(result, ParsedArgs::default(), "{long} changed nothing");
+            assert!(result.data.is_empty(), "{long} left its value as data");
+
+            if let Some(short) = spec.short {
+                let short = format!("-{short}");
+                assert_eq!(
+                    parse_with_value(&short),
+                    result,
+                    "{short} differs from {long}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn registered_flags_are_unique() {
+        for (index, spec) in FLAGS.iter().enumerate() {
+            for other in FLAGS.iter().skip(index.saturating_add(1)) {
+                assert_ne!(spec.flag, other.flag);
+                assert_ne!(spec.long, other.long);
+                assert!(spec.short.is_none() || spec.short != other.short);
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_flag_suggests_closest_flag() {
+        let err = parse_args_from(args(&["--forc"])).unwrap_err();
+        assert_eq!(err, ArgError::UnknownFlag("--forc".to_string()));
+        assert_eq!(
+            err.to_string(),
+            "unknown flag --forc (did you mean --force?)"
+        );
+
+        assert_eq!(suggest_flag("--sav"), Some("save"));
+        assert_eq!(suggest_flag("--dry"), Some("dry-run"));
+        assert_eq!(suggest_flag("--outptu"), Some("output"));
+        assert_eq!(suggest_flag("--input=stdin"), Some("input"));
+        assert_eq!(suggest_flag("--unknown"), None);
+        assert_eq!(suggest_flag("-x"), None);
+    }
+
+    #[test]
+    fn unknown_flag_without_suggestion_mentions_double_dash() {
+        let err = parse_args_from(args(&["--unknown"])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown flag --unknown (use -- to pass it as data)"
+        );
+    }
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("force", "force"), 0);
+        assert_eq!(edit_distance("forc", "force"), 1);
+        assert_eq!(edit_distance("fprce", "force"), 1);
+        assert_eq!(edit_distance("", "save"), 4);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
 }
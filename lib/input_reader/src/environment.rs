@@ -0,0 +1,173 @@
+//! The outside world the reader interacts with.
+//!
+//! Everything that depends on how the program was started, like the executable name,
+//! the arguments, stdin and the current directory, is reached through an
+//! [`Environment`]. Production code uses [`RealEnvironment`], while tests can script
+//! their own to exercise the whole reading flow without touching the real process.
+//!
+//! The system clipboard is reached through a [`Clipboard`], which mirrors the part of
+//! `arboard::Clipboard` the reader uses, so tests can replace it with a mock.
+
+use crate::paths::find_working_dir;
+use std::{
+    env,
+    io::{self, IsTerminal, Write},
+    path::PathBuf,
+};
+
+/// The process state the reader depends on.
+///
+/// Methods take `&self`, so implementations that record what happened need interior
+/// mutability.
+pub trait Environment {
+    /// The path of the running executable, whose name is the puzzle identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path can't be determined.
+    fn current_exe(&self) -> io::Result<PathBuf>;
+
+    /// The command-line arguments, without the executable itself.
+    fn args(&self) -> Vec<String>;
+
+    /// The value of the environment variable `key`, if it's set.
+    fn var(&self, key: &str) -> Option<String>;
+
+    /// The directory the search for an input file starts from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be determined.
+    fn current_dir(&self) -> io::Result<PathBuf>;
+
+    /// The directory input files are saved to when none is found, if there is one.
+    fn default_input_dir(&self) -> Option<PathBuf>;
+
+    /// Reads the next line of stdin into `buf`, keeping its line ending.
+    ///
+    /// Returns the number of bytes read, which is `0` at the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stdin can't be read.
+    fn read_stdin_line(&self, buf: &mut String) -> io::Result<usize>;
+
+    /// Whether stdin is connected to a terminal.
+    fn stdin_is_terminal(&self) -> bool;
+
+    /// Asks the user a yes or no question, returning `true` for yes.
+    fn confirm(&self, prompt: &str) -> bool;
+
+    /// Opens the system clipboard, like `arboard::Clipboard::new`.
+    ///
+    /// Defaults to an error, for environments without a clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there's no clipboard.
+    fn clipboard(&self) -> io::Result<Box<dyn Clipboard + '_>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "no clipboard available",
+        ))
+    }
+
+    /// Where normal output is written.
+    fn stdout(&self) -> Box<dyn Write + '_>;
+
+    /// Where warnings and machine-readable output are written.
+    ///
+    /// The writer doesn't borrow the environment, as warnings about file input are written
+    /// at the end of the file, after the reader has returned.
+    fn stderr(&self) -> Box<dyn Write + Send>;
+}
+
+/// A system clipboard, with the interface of `arboard::Clipboard` the reader uses.
+pub trait Clipboard {
+    /// Gets the text on the clipboard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the clipboard can't be read, or it doesn't hold text.
+    fn get_text(&mut self) -> io::Result<String>;
+}
+
+#[cfg(feature = "clipboard")]
+impl Clipboard for arboard::Clipboard {
+    fn get_text(&mut self) -> io::Result<String> {
+        arboard::Clipboard::get_text(self).map_err(io::Error::other)
+    }
+}
+
+/// The environment of the running process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealEnvironment;
+
+impl Environment for RealEnvironment {
+    fn current_exe(&self) -> io::Result<PathBuf> {
+        env::current_exe()
+    }
+
+    fn args(&self) -> Vec<String> {
+        env::args().skip(1).collect()
+    }
+
+    fn var(&self, key: &str) -> Option<String> {
+        env::var_os(key).map(|value| value.to_string_lossy().into_owned())
+    }
+
+    fn current_dir(&self) -> io::Result<PathBuf> {
+        env::current_dir()
+    }
+
+    fn default_input_dir(&self) -> Option<PathBuf> {
+        find_working_dir().ok()
+    }
+
+    fn read_stdin_line(&self, buf: &mut String) -> io::Result<usize> {
+        io::stdin().read_line(buf)
+    }
+
+    fn stdin_is_terminal(&self) -> bool {
+        io::stdin().is_terminal()
+    }
+
+    /// Prompts on stdout and reads the answer from stdin.
+    ///
+    /// Only `y` and `yes`, in any case, count as yes.
+    fn confirm(&self, prompt: &str) -> bool {
+        let mut stdout = io::stdout();
+        let _ = writeln!(stdout, "{prompt}");
+        stdout.flush().ok();
+
+        let mut response = String::new();
+        if io::stdin().read_line(&mut response).is_ok() {
+            let response = response.trim().to_lowercase();
+            response == "y" || response == "yes"
+        } else {
+            false
+        }
+    }
+
+    #[cfg(feature = "clipboard")]
+    fn clipboard(&self) -> io::Result<Box<dyn Clipboard + '_>> {
+        let clipboard = arboard::Clipboard::new().map_err(io::Error::other)?;
+        Ok(Box::new(clipboard))
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    fn clipboard(&self) -> io::Result<Box<dyn Clipboard + '_>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "built without the clipboard feature",
+        ))
+    }
+
+    fn stdout(&self) -> Box<dyn Write + '_> {
+        Box::new(io::stdout())
+    }
+
+    fn stderr(&self) -> Box<dyn Write + Send> {
+        Box::new(io::stderr())
+    }
+}
@@ -0,0 +1,138 @@
+//! Fetching missing input files with a user-supplied command.
+//!
+//! Fetching is opt-in: it only happens when [`FETCH_COMMAND_VAR`] holds a command
+//! template. The template's `{identifier}` and `{path}` placeholders are replaced with
+//! the puzzle identifier and the path the input file is expected at, then the command
+//! is run through the platform shell. Placeholders are substituted as-is, so the
+//! template should quote them if the path may contain spaces.
+
+use crate::environment::Environment;
+use std::{
+    fmt, io,
+    path::Path,
+    process::{Command, ExitStatus},
+};
+
+/// The environment variable holding the fetch command template.
+pub(crate) const FETCH_COMMAND_VAR: &str = "AOC_FETCH_CMD";
+
+/// Why fetching the input failed.
+#[derive(Debug)]
+pub(crate) enum FetchError {
+    /// The command couldn't be started.
+    Spawn(io::Error),
+    /// The command exited unsuccessfully.
+    Status(ExitStatus),
+    /// The command succeeded, but didn't create the input file.
+    Missing,
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Spawn(err) => write!(f, "failed to run fetch command: {err}"),
+            FetchError::Status(status) => write!(f, "fetch command failed ({status})"),
+            FetchError::Missing => write!(f, "fetch command did not create the input file"),
+        }
+    }
+}
+
+/// Gets the fetch command template from the environment, if one is configured.
+pub(crate) fn fetch_command_from_env(env: &dyn Environment) -> Option<String> {
+    env.var(FETCH_COMMAND_VAR)
+        .filter(|template| !template.trim().is_empty())
+}
+
+/// Runs the fetch command for `identifier`, which should create the file at `path`.
+///
+/// The command's stdout is redirected to stderr, leaving stdout to the solution.
+pub(crate) fn fetch_input(template: &str, identifier: &str, path: &Path) -> Result<(), FetchError> {
+    let command = substitute_placeholders(template, identifier, path);
+
+    let status = shell(&command)
+        .stdout(io::stderr())
+        .status()
+        .map_err(FetchError::Spawn)?;
+
+    if !status.success() {
+        return Err(FetchError::Status(status));
+    }
+
+    if !path.is_file() {
+        return Err(FetchError::Missing);
+    }
+
+    Ok(())
+}
+
+/// Replaces the `{identifier}` and `{path}` placeholders in `template`.
+fn substitute_placeholders(template: &str, identifier: &str, path: &Path) -> String {
+    template
+        .replace("{identifier}", identifier)
+        .replace("{path}", &path.display().to_string())
+}
+
+/// Creates a command running `command` through the platform shell.
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.args(["/C", command]);
+    shell
+}
+
+/// Creates a command running `command` through the platform shell.
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.args(["-c", command]);
+    shell
+}
+
+#[cfg(all(test, unix))]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn substitute_placeholders_replaces_every_occurrence() {
+        let command = substitute_placeholders(
+            "fetch {identifier} > {path} && echo {identifier}",
+            "day07",
+            Path::new("input/day07.txt"),
+        );
+        assert_eq!(command, "fetch day07 > input/day07.txt && echo day07");
+    }
+
+    #[test]
+    fn fetch_input_copies_fixture() {
+        let temp_dir = TempDir::new().unwrap();
+        let fixture = temp_dir.path().join("fixture.txt");
+        let path = temp_dir.path().join("day07.txt");
+        fs::write(&fixture, "fetched\n").unwrap();
+
+        let template = format!("cp '{}' '{{path}}'", fixture.display());
+        fetch_input(&template, "day07", &path).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fetched\n");
+    }
+
+    #[test]
+    fn fetch_input_reports_failing_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("day07.txt");
+
+        let result = fetch_input("exit 1", "day07", &path);
+        assert!(matches!(result, Err(FetchError::Status(_))));
+    }
+
+    #[test]
+    fn fetch_input_reports_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("day07.txt");
+
+        let result = fetch_input("true", "day07", &path);
+        assert!(matches!(result, Err(FetchError::Missing)));
+    }
+}
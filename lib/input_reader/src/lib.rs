@@ -7,24 +7,44 @@
 //!
 //! The main entry point is [`read_input`], which handles argument parsing
 //! and returns input that can be consumed as lines or characters.
+//!
+//! While reading, the input is checked for problems that tend to cause wrong answers,
+//! like trailing whitespace or mixed line endings. These are printed as warnings unless
+//! `AOC_QUIET` is set, and [`read_input_with_warnings`] returns them as well. File input
+//! is checked while it's consumed, so its warnings come at the end of the file.
+//!
+//! Solutions that take their own parameters, like `--seconds 2503`, can get them with
+//! [`read_options`].
+//!
+//! The process state the reader depends on is reached through an [`Environment`]. Use
+//! [`read_input_with`] with your own implementation to run the reader against a scripted
+//! environment, for example in tests.
 
 mod args;
+mod environment;
+mod fetch;
 mod paths;
+mod puzzle_id;
+mod settings;
 mod terminal;
 mod types;
-
-use crate::args::parse_args;
-use crate::paths::{find_input_file_path, get_identifier};
-use crate::terminal::{
-    print_help, print_input_saved, print_no_input, print_nothing_to_save, print_request_for_input,
-    print_save_aborted, print_save_refused, print_truncation_warning,
-    prompt_overwrite_confirmation,
-};
-pub use crate::types::{Error, Input, Outcome};
+mod warnings;
+
+pub use crate::args::{ArgError, Options};
+use crate::args::{ParsedArgs, parse_args};
+pub use crate::environment::{Clipboard, Environment, RealEnvironment};
+use crate::fetch::fetch_input;
+use crate::paths::{InputName, find_input_file_path, get_identifier};
+pub use crate::puzzle_id::{PuzzleId, parse_identifier};
+use crate::settings::Settings;
+use crate::terminal::{Event, Terminal, print_help};
+pub use crate::types::{Error, FileReader, Input, Outcome, ParseLinesError};
 use crate::types::{InputMethod, InternalError};
+use crate::warnings::InputInspector;
+pub use crate::warnings::{InputWarning, InputWarnings};
 use std::{
-    fs::File,
-    io::{self, BufRead, BufReader, IsTerminal, Write},
+    fs::{self, File},
+    io::{self, BufReader, Read, Seek, Write},
 };
 
 /// Reads input for a puzzle from various sources.
@@ -38,94 +58,318 @@ use std::{
 ///
 /// * `Ok(Outcome::Exit)` - The caller should exit the application
 /// * `Ok(Outcome::Continue(input))` - Input is ready for processing
-/// * `Err(Error)` - A path or I/O error occurred
+/// * `Ok(Outcome::DryRun(input))` - Input was loaded with `--dry-run` and shouldn't be solved
+/// * `Err(Error)` - The arguments were invalid, or a path or I/O error occurred
 pub fn read_input() -> Result<Outcome, Error> {
-    let identifier = get_identifier()?;
-    let args = parse_args();
+    read_input_with(&RealEnvironment)
+}
+
+/// Reads input like [`read_input`], but from the given environment.
+///
+/// [`read_input`] is this function with a [`RealEnvironment`].
+///
+/// # Returns
+///
+/// The same as [`read_input`].
+pub fn read_input_with(env: &dyn Environment) -> Result<Outcome, Error> {
+    read_input_and_warnings(env).map(|(outcome, _)| outcome)
+}
+
+/// Reads input like [`read_input`], also returning warnings about the input.
+///
+/// Each line is checked as it's read, so every source is only read once. File input is
+/// streamed, and checked while the caller consumes it, so its warnings are only known at
+/// the end of the file. The warnings are printed to stderr unless `AOC_QUIET` is set, and
+/// returned either way.
+///
+/// # Returns
+///
+/// The [`Outcome`] of [`read_input`], along with the warnings found in the input. For
+/// file input, they are complete once the input has been read to the end. When the
+/// caller should exit there was no input to check, so there are no warnings.
+pub fn read_input_with_warnings() -> Result<(Outcome, InputWarnings), Error> {
+    read_input_and_warnings(&RealEnvironment)
+}
+
+/// Reads the solution-specific options from the command-line arguments.
+///
+/// These are the `--key value` and `--key=value` pairs that aren't flags of the reader
+/// itself, so they never end up in the input. Everything after `--` is data, not options.
+///
+/// # Errors
+///
+/// Returns an [`ArgError`] if the arguments are invalid, like [`read_input`] does.
+pub fn read_options() -> Result<Options, ArgError> {
+    parse_args(&RealEnvironment).map(|args| args.options)
+}
+
+/// Reads input from `env`, returning it together with warnings about it.
+fn read_input_and_warnings(env: &dyn Environment) -> Result<(Outcome, InputWarnings), Error> {
+    let identifier = get_identifier(env)?;
+    let args = parse_args(env)?;
 
     // Print help and exit
     if args.help {
-        print_help(&identifier);
-        return Ok(Outcome::Exit);
+        print_help(&mut env.stdout(), &identifier.name);
+        return Ok((Outcome::Exit, InputWarnings::default()));
+    }
+
+    let settings = Settings::from_env(env);
+    let mut terminal = Terminal::with_writers(args.output, env.stdout(), env.stderr());
+
+    if args.verbose {
+        terminal.notify(&Event::Identifier {
+            exe_name: &identifier.exe_name,
+            identifier: &identifier.name,
+        });
     }
 
+    let name = InputName::new(&identifier.name, settings.input_template.as_deref())?;
+    load_input(&name, &args, &settings, env, &mut terminal)
+}
+
+/// Loads input as described by `args` and `settings`, reporting to `terminal`.
+fn load_input<O: Write, E: Write>(
+    name: &InputName,
+    args: &ParsedArgs,
+    settings: &Settings,
+    env: &dyn Environment,
+    terminal: &mut Terminal<O, E>,
+) -> Result<(Outcome, InputWarnings), Error> {
+    let mut inspector = InputInspector::new(settings.long_line_threshold)
+        .warn_leading_whitespace(settings.warn_leading_whitespace);
+
     // Get the input and the method that was actually used
     let read_result = match args.input {
-        InputMethod::Auto => read_input_auto(&identifier, &args.data),
-        InputMethod::File => read_input_file(&identifier),
-        InputMethod::Args => read_input_args(&args.data),
-        InputMethod::Stdin => read_input_stdin(),
+        InputMethod::Auto => read_input_auto(
+            name,
+            args,
+            settings.fetch_command.as_deref(),
+            env,
+            &mut inspector,
+            terminal,
+        ),
+        InputMethod::File => read_input_file(name, args, env, terminal),
+        InputMethod::Args => read_input_args(&args.data, &mut inspector),
+        InputMethod::Stdin | InputMethod::Pipe => read_input_stdin(env, &mut inspector, terminal),
+        InputMethod::Clipboard => read_input_clipboard(env, &mut inspector, terminal),
     };
 
     // Handle internal errors and break apart the method and input
-    let (method, input) = match read_result {
+    let (method, mut input) = match read_result {
         Ok(read_result) => read_result,
         Err(InternalError::NoInput) => {
-            print_no_input();
-            return Ok(Outcome::Exit);
+            terminal.notify(&Event::NoInput);
+            return Ok((Outcome::Exit, InputWarnings::default()));
         }
         Err(InternalError::Path(e)) => return Err(e),
         Err(InternalError::Io(e)) => return Err(e.into()),
     };
 
+    let warnings = InputWarnings::default();
+    if let Input::File(reader) = &mut input {
+        // File input is inspected as the caller reads it, after this function returns
+        let (format, quiet, shared) = (args.output, settings.quiet, warnings.clone());
+        let stderr = env.stderr();
+        reader.inspect_with(inspector, move |found| {
+            let mut terminal = Terminal::with_writers(format, io::sink(), stderr);
+            report_warnings(&mut terminal, quiet, found, &shared);
+        });
+    } else {
+        report_warnings(terminal, settings.quiet, inspector.finish(), &warnings);
+    }
+
     // Check for potential truncation issues.
-    let refuse_save = may_be_truncated(method, &input);
+    let refuse_save = may_be_truncated(terminal, method, &input);
 
     // Save to file
     if args.save {
-        save_input_to_file(refuse_save, &input, &identifier, args.force)?;
+        save_input_to_file(env, terminal, refuse_save, &input, name, args)?;
     }
 
-    Ok(input.into())
+    let outcome = if args.dry_run {
+        Outcome::DryRun(input)
+    } else {
+        input.into()
+    };
+
+    Ok((outcome, warnings))
 }
 
-fn read_input_auto(
-    identifier: &str,
-    data: &[String],
+/// Reports the `found` warnings to `terminal` unless `quiet`, and keeps them in `warnings`.
+fn report_warnings<O: Write, E: Write>(
+    terminal: &mut Terminal<O, E>,
+    quiet: bool,
+    found: Vec<InputWarning>,
+    warnings: &InputWarnings,
+) {
+    if !quiet {
+        for warning in &found {
+            terminal.notify(&Event::InputWarning { warning });
+        }
+    }
+
+    warnings.set(found);
+}
+
+/// Tries each input method in turn: file, args, fetching the file, then stdin.
+///
+/// Fetching is skipped unless a `fetch_command` template is configured. The inspector is
+/// reset before each fallback that uses it, so only the warnings of the method that
+/// succeeds are kept. File input is inspected later, as it's read.
+fn read_input_auto<O: Write, E: Write>(
+    name: &InputName,
+    args: &ParsedArgs,
+    fetch_command: Option<&str>,
+    env: &dyn Environment,
+    inspector: &mut InputInspector,
+    terminal: &mut Terminal<O, E>,
+) -> Result<(InputMethod, Input), InternalError> {
+    read_input_file(name, args, env, terminal)
+        .or_else(|_| {
+            inspector.reset();
+            read_input_args(&args.data, inspector)
+        })
+        .or_else(|_| match fetch_command {
+            Some(template) => read_input_fetched(template, name, args, env, terminal),
+            None => Err(InternalError::NoInput),
+        })
+        .or_else(|_| {
+            inspector.reset();
+            read_input_stdin(env, inspector, terminal)
+        })
+}
+
+/// Runs the fetch command to create the input file, then reads it.
+///
+/// A failed fetch is reported as a warning and treated as no input, so the caller can
+/// fall back to another method.
+fn read_input_fetched<O: Write, E: Write>(
+    template: &str,
+    name: &InputName,
+    args: &ParsedArgs,
+    env: &dyn Environment,
+    terminal: &mut Terminal<O, E>,
 ) -> Result<(InputMethod, Input), InternalError> {
-    read_input_file(identifier)
-        .or_else(|_| read_input_args(data))
-        .or_else(|_| read_input_stdin())
+    let input_file_path = find_input_file_path(name, args.input_path.as_deref(), env)?;
+
+    if let Err(err) = fetch_input(template, &name.identifier, &input_file_path) {
+        terminal.notify(&Event::FetchFailed {
+            reason: &err.to_string(),
+        });
+        return Err(InternalError::NoInput);
+    }
+
+    read_input_file(name, args, env, terminal)
 }
 
-fn read_input_file(identifier: &str) -> Result<(InputMethod, Input), InternalError> {
-    let input_file_path = find_input_file_path(identifier)?;
+/// Reads input from the input file, if there is one.
+///
+/// A file that's empty or only whitespace counts as no input, unless `--allow-empty`
+/// was given, as it's usually one created before pasting the input into it.
+fn read_input_file<O: Write, E: Write>(
+    name: &InputName,
+    args: &ParsedArgs,
+    env: &dyn Environment,
+    terminal: &mut Terminal<O, E>,
+) -> Result<(InputMethod, Input), InternalError> {
+    let input_file_path = find_input_file_path(name, args.input_path.as_deref(), env)?;
 
     if !input_file_path.exists() {
         return Err(InternalError::NoInput);
     }
 
-    let input_file = File::open(&input_file_path).map_err(|e| InternalError::Path(e.into()))?;
-    let reader = BufReader::new(input_file);
+    if args.verbose {
+        terminal.notify(&Event::InputPath {
+            path: &input_file_path,
+        });
+    }
+
+    let mut input_file = File::open(&input_file_path).map_err(|e| InternalError::Path(e.into()))?;
 
+    if !args.allow_empty && !has_content(&mut input_file).map_err(InternalError::Io)? {
+        return Err(InternalError::NoInput);
+    }
+
+    let reader = FileReader::new(BufReader::new(input_file));
     Ok((InputMethod::File, Input::File(reader)))
 }
 
-fn read_input_args(data: &[String]) -> Result<(InputMethod, Input), InternalError> {
+/// Whether the file has anything other than whitespace, leaving it at the start.
+///
+/// Stops at the first byte that isn't whitespace, so the file isn't read twice.
+fn has_content(file: &mut File) -> io::Result<bool> {
+    let first_content = BufReader::new(&*file)
+        .bytes()
+        .find(|byte| {
+            byte.as_ref()
+                .map_or(true, |byte| !byte.is_ascii_whitespace())
+        })
+        .transpose()?;
+
+    file.rewind()?;
+    Ok(first_content.is_some())
+}
+
+/// Strips the line ending from `raw_line` like `BufRead::lines` does.
+fn strip_line_ending(raw_line: &str) -> &str {
+    raw_line
+        .strip_suffix('\n')
+        .map_or(raw_line, |line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+fn read_input_args(
+    data: &[String],
+    inspector: &mut InputInspector,
+) -> Result<(InputMethod, Input), InternalError> {
     if data.is_empty() {
-        Err(InternalError::NoInput)
-    } else {
-        Ok((InputMethod::Args, Input::Memory(data.to_vec())))
+        return Err(InternalError::NoInput);
+    }
+
+    for line in data {
+        inspector.inspect(line);
     }
+
+    Ok((InputMethod::Args, Input::Memory(data.to_vec())))
 }
 
-#[expect(
-    clippy::arithmetic_side_effects,
-    reason = "blank_count is reset before reaching overflow"
-)]
-fn read_input_stdin() -> Result<(InputMethod, Input), InternalError> {
-    print_request_for_input();
+/// Reads input from stdin, until the end of the input.
+///
+/// Only prompts for input when stdin is a terminal, where two blank lines in a row also
+/// end the input. Otherwise the input is piped, read to the end even if it has blank
+/// lines in a row, and reported as [`InputMethod::Pipe`].
+fn read_input_stdin<O: Write, E: Write>(
+    env: &dyn Environment,
+    inspector: &mut InputInspector,
+    terminal: &mut Terminal<O, E>,
+) -> Result<(InputMethod, Input), InternalError> {
+    let method = if env.stdin_is_terminal() {
+        terminal.notify(&Event::AwaitingInput);
+        InputMethod::Stdin
+    } else {
+        InputMethod::Pipe
+    };
 
-    let stdin = io::stdin();
     let mut lines = Vec::new();
-    let mut blank_count = 0;
+    let mut blank_count = 0_usize;
+    let mut raw_line = String::new();
+
+    loop {
+        raw_line.clear();
+        if env
+            .read_stdin_line(&mut raw_line)
+            .map_err(InternalError::Io)?
+            == 0
+        {
+            break;
+        }
+        inspector.inspect(&raw_line);
 
-    for line in stdin.lock().lines() {
-        let line = line.map_err(InternalError::Io)?;
+        let line = strip_line_ending(&raw_line).to_string();
 
         if line.is_empty() {
-            blank_count += 1;
-            if blank_count >= 2 {
+            blank_count = blank_count.saturating_add(1);
+            if blank_count >= 2 && method == InputMethod::Stdin {
                 break;
             }
             lines.push(line);
@@ -136,10 +380,9 @@ fn read_input_stdin() -> Result<(InputMethod, Input), InternalError> {
     }
 
     // Remove leading and trailing blank lines
-    if lines.first().is_some_and(String::is_empty) {
-        lines.remove(0);
-    }
-    if lines.last().is_some_and(String::is_empty) {
+    let leading = lines.iter().take_while(|line| line.is_empty()).count();
+    lines.drain(..leading);
+    while lines.last().is_some_and(String::is_empty) {
         lines.pop();
     }
 
@@ -147,44 +390,90 @@ fn read_input_stdin() -> Result<(InputMethod, Input), InternalError> {
         return Err(InternalError::NoInput);
     }
 
-    Ok((InputMethod::Stdin, Input::Memory(lines)))
+    Ok((method, Input::Memory(lines)))
+}
+
+/// Reads input from the clipboard, one line per line of the copied text.
+///
+/// A clipboard that can't be read is reported as a warning and treated as no input.
+fn read_input_clipboard<O: Write, E: Write>(
+    env: &dyn Environment,
+    inspector: &mut InputInspector,
+    terminal: &mut Terminal<O, E>,
+) -> Result<(InputMethod, Input), InternalError> {
+    let text = match env
+        .clipboard()
+        .and_then(|mut clipboard| clipboard.get_text())
+    {
+        Ok(text) => text,
+        Err(err) => {
+            terminal.notify(&Event::ClipboardFailed {
+                reason: &err.to_string(),
+            });
+            return Err(InternalError::NoInput);
+        }
+    };
+
+    if text.trim().is_empty() {
+        return Err(InternalError::NoInput);
+    }
+
+    for raw_line in text.split_inclusive('\n') {
+        inspector.inspect(raw_line);
+    }
+
+    let lines = text.lines().map(String::from).collect();
+    Ok((InputMethod::Clipboard, Input::Memory(lines)))
 }
 
-fn save_input_to_file(
+fn save_input_to_file<O: Write, E: Write>(
+    env: &dyn Environment,
+    terminal: &mut Terminal<O, E>,
     refuse: bool,
     input: &Input,
-    identifier: &str,
-    force: bool,
+    name: &InputName,
+    args: &ParsedArgs,
 ) -> Result<(), Error> {
     if refuse {
-        print_save_refused();
+        terminal.notify(&Event::SaveRefused);
         return Ok(());
     }
 
     // Only save if input is from memory (args/stdin), not from file
     if let Input::Memory(lines) = input {
-        let input_file_path = find_input_file_path(identifier).map_err(|e| match e {
-            InternalError::NoInput | InternalError::Io(_) => {
-                unreachable!("find_input_file_path never returns NoInput or Io")
-            }
-            InternalError::Path(p) => p,
-        })?;
+        let input_file_path =
+            find_input_file_path(name, args.input_path.as_deref(), env).map_err(|e| match e {
+                InternalError::NoInput | InternalError::Io(_) => {
+                    unreachable!("find_input_file_path never returns NoInput or Io")
+                }
+                InternalError::Path(p) => p,
+            })?;
 
         // Check if file exists and prompt for confirmation if needed
-        if input_file_path.exists() && !force && !prompt_overwrite_confirmation() {
+        if input_file_path.exists()
+            && !args.force
+            && !terminal.confirm_overwrite(&input_file_path, env)
+        {
             // User declined to overwrite, skip saving
-            print_save_aborted();
+            terminal.notify(&Event::SaveAborted);
             return Ok(());
         }
 
+        // A templated path can name directories that don't exist yet
+        if let Some(parent) = input_file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
         let mut file = File::create(&input_file_path)?;
         for line in lines {
             writeln!(file, "{line}")?;
         }
 
-        print_input_saved(identifier);
+        terminal.notify(&Event::InputSaved {
+            identifier: &name.identifier,
+            path: &input_file_path,
+        });
     } else {
-        print_nothing_to_save();
+        terminal.notify(&Event::NothingToSave);
     }
 
     Ok(())
@@ -192,22 +481,29 @@ fn save_input_to_file(
 
 /// Checks if saving should be refused due to potential TTY truncation.
 ///
-/// Returns `true` if the input came from a TTY and any line is close to the
-/// `N_TTY_BUF_SIZE` limit (4096 bytes), indicating potential truncation.
-fn may_be_truncated(input_method: InputMethod, input: &Input) -> bool {
-    if input_method == InputMethod::Stdin
+/// Returns `true` if the input came from a TTY ([`InputMethod::Stdin`]) or the clipboard,
+/// and any line is close to the `N_TTY_BUF_SIZE` limit (4096 bytes), indicating potential
+/// truncation. Clipboard text is checked too, as it can be arbitrarily long. Piped input
+/// isn't limited by the TTY buffer.
+fn may_be_truncated<O: Write, E: Write>(
+    terminal: &mut Terminal<O, E>,
+    input_method: InputMethod,
+    input: &Input,
+) -> bool {
+    if matches!(input_method, InputMethod::Stdin | InputMethod::Clipboard)
         && let Input::Memory(lines) = input
-        && io::stdin().is_terminal()
     {
         // N_TTY_BUF_SIZE is 4096 in the Linux kernel
         // Use a threshold close to it to detect potential truncation
         const TTY_TRUNCATION_THRESHOLD: usize = 4000;
 
-        if lines
+        if let Some(line) = lines
             .iter()
-            .any(|line| line.len() >= TTY_TRUNCATION_THRESHOLD)
+            .find(|line| line.len() >= TTY_TRUNCATION_THRESHOLD)
         {
-            print_truncation_warning();
+            terminal.notify(&Event::TruncationWarning {
+                line_len: line.len(),
+            });
             return true;
         }
     }
@@ -219,11 +515,57 @@ fn may_be_truncated(input_method: InputMethod, input: &Input) -> bool {
 #[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
 mod tests {
     use super::*;
+    use crate::types::OutputFormat;
+    use serde_json::{Value, json};
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    /// The input name of `day07`, without a template.
+    fn day07() -> InputName {
+        InputName::new("day07", None).unwrap()
+    }
+
+    /// Runs `load_input` in JSON mode, returning the outcome and the emitted events.
+    fn run_json(args: &ParsedArgs) -> (Outcome, Vec<Value>) {
+        let (outcome, _, events) = run_json_with(args, &Settings::default());
+        (outcome, events)
+    }
+
+    /// Runs `load_input` in JSON mode, also returning the input warnings.
+    fn run_json_with(
+        args: &ParsedArgs,
+        settings: &Settings,
+    ) -> (Outcome, Vec<InputWarning>, Vec<Value>) {
+        let mut terminal = Terminal::with_writers(OutputFormat::Json, Vec::new(), Vec::new());
+        let (outcome, warnings) =
+            load_input(&day07(), args, settings, &RealEnvironment, &mut terminal).unwrap();
+
+        let (stdout, stderr) = terminal.into_writers();
+        assert!(stdout.is_empty(), "JSON mode should leave stdout alone");
+
+        let events = String::from_utf8(stderr)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        (outcome, warnings.get(), events)
+    }
+
+    fn memory_args(input_path: &Path, force: bool) -> ParsedArgs {
+        ParsedArgs {
+            input: InputMethod::Args,
+            save: true,
+            force,
+            input_path: Some(input_path.to_path_buf()),
+            data: vec!["line1".to_string(), "line2".to_string()],
+            ..ParsedArgs::default()
+        }
+    }
 
     #[test]
     fn read_input_args_returns_memory_input() {
         let data = vec!["line1".to_string(), "line2".to_string()];
-        let result = read_input_args(&data);
+        let result = read_input_args(&data, &mut InputInspector::default());
         assert!(result.is_ok());
 
         let (_, input) = result.unwrap();
@@ -234,7 +576,318 @@ mod tests {
     #[test]
     fn read_input_args_returns_no_input_for_empty() {
         let data: Vec<String> = vec![];
-        let result = read_input_args(&data);
+        let result = read_input_args(&data, &mut InputInspector::default());
         assert!(matches!(result, Err(InternalError::NoInput)));
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn auto_reads_fetched_input_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let fixture = temp_dir.path().join("fixture.txt");
+        let input_path = temp_dir.path().join("day07.txt");
+        fs::write(&fixture, "fetched\n").unwrap();
+
+        let args = ParsedArgs {
+            input_path: Some(input_path.clone()),
+            ..ParsedArgs::default()
+        };
+        let template = format!("cp '{}' '{{path}}'", fixture.display());
+        let mut terminal = Terminal::with_writers(OutputFormat::Json, Vec::new(), Vec::new());
+
+        let (method, input) = read_input_auto(
+            &day07(),
+            &args,
+            Some(&template),
+            &RealEnvironment,
+            &mut InputInspector::default(),
+            &mut terminal,
+        )
+        .unwrap();
+
+        assert_eq!(method, InputMethod::File);
+        let lines: Vec<String> = input.lines().map(|r| r.unwrap()).collect();
+        assert_eq!(lines, vec!["fetched"]);
+        assert_eq!(terminal.into_writers(), (vec![], vec![]));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn auto_prefers_args_over_fetching() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+
+        let args = ParsedArgs {
+            input_path: Some(input_path.clone()),
+            data: vec!["from args".to_string()],
+            ..ParsedArgs::default()
+        };
+        let mut terminal = Terminal::with_writers(OutputFormat::Json, Vec::new(), Vec::new());
+
+        let (method, _) = read_input_auto(
+            &day07(),
+            &args,
+            Some("echo fetched > '{path}'"),
+            &RealEnvironment,
+            &mut InputInspector::default(),
+            &mut terminal,
+        )
+        .unwrap();
+
+        assert_eq!(method, InputMethod::Args);
+        assert!(!input_path.exists(), "fetch command should not have run");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failed_fetch_warns_and_reports_no_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let args = ParsedArgs {
+            input_path: Some(temp_dir.path().join("day07.txt")),
+            ..ParsedArgs::default()
+        };
+
+        for template in ["exit 1", "true"] {
+            let mut terminal = Terminal::with_writers(OutputFormat::Json, Vec::new(), Vec::new());
+
+            let result =
+                read_input_fetched(template, &day07(), &args, &RealEnvironment, &mut terminal);
+            assert!(matches!(result, Err(InternalError::NoInput)));
+
+            let (_, stderr) = terminal.into_writers();
+            let event: Value = serde_json::from_slice(&stderr).unwrap();
+            assert_eq!(event.get("event"), Some(&json!("fetch_failed")));
+        }
+    }
+
+    #[test]
+    fn json_save_flow_reports_input_saved() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+
+        let (outcome, events) = run_json(&memory_args(&input_path, false));
+
+        assert!(matches!(outcome, Outcome::Continue(_)));
+        assert_eq!(
+            events,
+            [json!({
+                "event": "input_saved",
+                "identifier": "day07",
+                "path": input_path.display().to_string(),
+            })]
+        );
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "line1\nline2\n");
+    }
+
+    #[test]
+    fn json_no_input_flow_reports_no_input() {
+        let args = ParsedArgs {
+            input: InputMethod::Args,
+            ..ParsedArgs::default()
+        };
+
+        let (outcome, events) = run_json(&args);
+
+        assert!(matches!(outcome, Outcome::Exit));
+        assert_eq!(events, [json!({ "event": "no_input" })]);
+    }
+
+    #[test]
+    fn json_refused_save_reports_save_refused() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+        let args = memory_args(&input_path, false);
+        let input = Input::Memory(args.data.clone());
+
+        let mut terminal = Terminal::with_writers(OutputFormat::Json, Vec::new(), Vec::new());
+        save_input_to_file(
+            &RealEnvironment,
+            &mut terminal,
+            true,
+            &input,
+            &day07(),
+            &args,
+        )
+        .unwrap();
+
+        let (_, stderr) = terminal.into_writers();
+        let event: Value = serde_json::from_slice(&stderr).unwrap();
+        assert_eq!(event, json!({ "event": "save_refused" }));
+        assert!(!input_path.exists());
+    }
+
+    #[test]
+    fn json_overwrite_is_auto_declined_without_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+        fs::write(&input_path, "existing\n").unwrap();
+
+        let (_, events) = run_json(&memory_args(&input_path, false));
+
+        assert_eq!(
+            events,
+            [
+                json!({
+                    "event": "overwrite_declined",
+                    "path": input_path.display().to_string(),
+                }),
+                json!({ "event": "save_aborted" }),
+            ]
+        );
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "existing\n");
+    }
+
+    #[test]
+    fn json_overwrite_is_allowed_with_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+        fs::write(&input_path, "existing\n").unwrap();
+
+        let (_, events) = run_json(&memory_args(&input_path, true));
+
+        let names: Vec<_> = events
+            .iter()
+            .filter_map(|event| event.get("event"))
+            .collect();
+        assert_eq!(names, ["input_saved"]);
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "line1\nline2\n");
+    }
+
+    #[test]
+    fn dry_run_still_saves_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+        let args = ParsedArgs {
+            dry_run: true,
+            ..memory_args(&input_path, false)
+        };
+
+        let (outcome, _) = run_json(&args);
+
+        let Outcome::DryRun(input) = outcome else {
+            panic!("expected a dry run");
+        };
+        assert_eq!(input.lines().count(), 2);
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "line1\nline2\n");
+    }
+
+    #[test]
+    fn memory_input_warnings_are_returned_and_reported() {
+        let args = ParsedArgs {
+            input: InputMethod::Args,
+            data: vec![
+                "clean".to_string(),
+                "trailing space ".to_string(),
+                "\tindented".to_string(),
+            ],
+            ..ParsedArgs::default()
+        };
+        let settings = Settings {
+            warn_leading_whitespace: true,
+            ..Settings::default()
+        };
+
+        let (_, warnings, events) = run_json_with(&args, &settings);
+
+        assert_eq!(
+            warnings,
+            vec![
+                InputWarning::TrailingWhitespace { line: 2 },
+                InputWarning::LeadingWhitespace { line: 3 },
+            ]
+        );
+        let lines: Vec<_> = events
+            .iter()
+            .filter(|event| event.get("event") == Some(&json!("input_warning")))
+            .filter_map(|event| event.get("line"))
+            .collect();
+        assert_eq!(lines, [&json!(2), &json!(3)]);
+    }
+
+    #[test]
+    fn clean_input_has_no_warnings() {
+        let args = ParsedArgs {
+            input: InputMethod::Args,
+            data: vec!["1 2 3".to_string(), "4 5 6".to_string()],
+            ..ParsedArgs::default()
+        };
+
+        let (_, warnings, events) = run_json_with(&args, &Settings::default());
+
+        assert_eq!(warnings, vec![]);
+        assert_eq!(events, Vec::<Value>::new());
+    }
+
+    #[test]
+    fn blank_file_warnings_are_dropped_when_falling_back_to_args() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+        fs::write(&input_path, "  \r\n\n").unwrap();
+
+        let args = ParsedArgs {
+            input_path: Some(input_path),
+            data: vec!["line1".to_string()],
+            ..ParsedArgs::default()
+        };
+
+        let (outcome, warnings, events) = run_json_with(&args, &Settings::default());
+
+        assert!(matches!(outcome, Outcome::Continue(Input::Memory(lines)) if lines == ["line1"]));
+        assert_eq!(warnings, vec![]);
+        assert_eq!(events, Vec::<Value>::new());
+    }
+
+    #[test]
+    fn quiet_keeps_warnings_out_of_output() {
+        let args = ParsedArgs {
+            input: InputMethod::Args,
+            data: vec!["trailing ".to_string()],
+            ..ParsedArgs::default()
+        };
+        let settings = Settings {
+            quiet: true,
+            ..Settings::default()
+        };
+
+        let (_, warnings, events) = run_json_with(&args, &settings);
+
+        assert_eq!(warnings, vec![InputWarning::TrailingWhitespace { line: 1 }]);
+        assert_eq!(events, Vec::<Value>::new());
+    }
+
+    #[test]
+    fn file_input_is_streamed_and_inspected_as_it_is_read() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_path = temp_dir.path().join("day07.txt");
+        fs::write(&input_path, "a\r\nb\nc ").unwrap();
+
+        let args = ParsedArgs {
+            input: InputMethod::File,
+            input_path: Some(input_path),
+            ..ParsedArgs::default()
+        };
+        let settings = Settings {
+            quiet: true,
+            ..Settings::default()
+        };
+        let mut terminal = Terminal::with_writers(OutputFormat::Json, Vec::new(), Vec::new());
+
+        let (outcome, warnings) =
+            load_input(&day07(), &args, &settings, &RealEnvironment, &mut terminal).unwrap();
+
+        let Outcome::Continue(input @ Input::File(_)) = outcome else {
+            panic!("expected file input");
+        };
+        assert_eq!(warnings.get(), vec![]);
+
+        let lines: Vec<String> = input.lines().map(|r| r.unwrap()).collect();
+        assert_eq!(lines, vec!["a", "b", "c "]);
+        assert_eq!(
+            warnings.get(),
+            vec![
+                InputWarning::MixedLineEndings { line: 2 },
+                InputWarning::TrailingWhitespace { line: 3 },
+            ]
+        );
+    }
 }
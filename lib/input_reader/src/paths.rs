@@ -1,7 +1,22 @@
 //! Path resolution for puzzle input files.
 //!
-//! This module provides functionality to locate the directory containing puzzle input files.
-//! It uses different strategies depending on the build configuration:
+//! This module provides functionality to locate puzzle input files. An input file is looked
+//! for in the following order:
+//!
+//! 1. An explicit path given with `--input-path`.
+//! 2. The current directory and up to [`MAX_SEARCH_DEPTH`] of its parents, stopping at the
+//!    repository root. Each directory is checked for the input file itself and for an `input`
+//!    subdirectory containing it. Release builds skip this step for their generic
+//!    `input.txt`, which could belong to another puzzle when found in a parent directory.
+//! 3. The default working directory, which depends on the build configuration.
+//!
+//! The subdirectory is called `input`, not `inputs`, to match the shared directory of the
+//! debug builds below, so the search from anywhere in the repository finds the same files.
+//!
+//! When `AOC_INPUT_TEMPLATE` is set and the identifier names a puzzle, the templated path,
+//! like `inputs/2015/07.txt`, is looked for before the usual file name in each step.
+//!
+//! The default working directory is found using different strategies:
 //!
 //! - **Debug builds**: Traverses up from `CARGO_MANIFEST_DIR` to find the repository root
 //!   (identified by a `.git` directory), then returns the `input` subdirectory. This shared
@@ -9,39 +24,206 @@
 //! - **Release builds**: Returns the parent directory of the current executable, where
 //!   input files are expected to be siblings of the binary.
 
+use crate::environment::Environment;
+use crate::puzzle_id::{expand_template, parse_identifier};
 use crate::types::{Error, InternalError};
-use std::{env, path::PathBuf};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+/// How many parent directories above the current directory are searched for input.
+const MAX_SEARCH_DEPTH: usize = 6;
+
+/// The length of the hash cargo appends to the names of test executables.
+const CARGO_HASH_LEN: usize = 16;
+
+/// The puzzle identifier, together with the executable name it was derived from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Identifier {
+    /// The file name of the executable, as it is.
+    pub exe_name: String,
+    /// The identifier derived from it, like `day07`.
+    pub name: String,
+}
 
 /// Gets the identifier from the current executable name.
 ///
-/// Extracts the file stem from `current_exe()`.
+/// Takes the file name of the environment's `current_exe()`, and derives the identifier
+/// from it with [`derive_identifier`].
 ///
 /// # Errors
 ///
 /// Returns [`Error::Io`] if the current executable path cannot be determined.
-/// Returns [`Error::NotFound`] if the executable name cannot be extracted.
-pub(crate) fn get_identifier() -> Result<String, Error> {
-    let exe_path = env::current_exe().map_err(Error::Io)?;
+/// Returns [`Error::NotFound`] if the path has no file name.
+/// Returns [`Error::InvalidIdentifier`] if no identifier can be derived from the name.
+pub(crate) fn get_identifier(env: &dyn Environment) -> Result<Identifier, Error> {
+    let exe_path = env.current_exe().map_err(Error::Io)?;
+    let exe_name = exe_path
+        .file_name()
+        .ok_or(Error::NotFound)?
+        .to_string_lossy()
+        .into_owned();
 
-    exe_path
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .map(String::from)
-        .ok_or(Error::NotFound)
+    match derive_identifier(&exe_name) {
+        Some(name) => Ok(Identifier { exe_name, name }),
+        None => Err(Error::InvalidIdentifier(exe_name)),
+    }
 }
 
-pub(crate) fn find_input_file_path(identifier: &str) -> Result<PathBuf, InternalError> {
-    let working_dir = find_working_dir()?;
+/// Derives the puzzle identifier from the executable name `exe_name`.
+///
+/// Strips a `.exe` or `.bin` extension, then a cargo hash suffix like
+/// `-5f3a9c1b2d4e6f80`. Returns `None` unless what remains starts with an ASCII letter
+/// or digit, and is made of only those, `_` and `-`.
+fn derive_identifier(exe_name: &str) -> Option<String> {
+    let name = exe_name
+        .strip_suffix(".exe")
+        .or_else(|| exe_name.strip_suffix(".bin"))
+        .unwrap_or(exe_name);
+    let name = strip_cargo_hash(name);
 
-    let input_file_path = if cfg!(debug_assertions) {
-        working_dir.join(format!("{identifier}.txt"))
-    } else {
-        working_dir.join("input.txt")
+    let is_valid = name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    is_valid.then(|| name.to_string())
+}
+
+/// Strips a trailing `-` followed by a cargo hash of [`CARGO_HASH_LEN`] hex digits.
+fn strip_cargo_hash(name: &str) -> &str {
+    match name.rsplit_once('-') {
+        Some((stem, hash))
+            if !stem.is_empty()
+                && hash.len() == CARGO_HASH_LEN
+                && hash.chars().all(|c| c.is_ascii_hexdigit()) =>
+        {
+            stem
+        }
+        _ => name,
+    }
+}
+
+/// The names the input file of a puzzle can go by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct InputName {
+    /// The puzzle identifier, like `day07`.
+    pub identifier: String,
+    /// The relative path from the input template, if one applies to the identifier.
+    pub templated: Option<PathBuf>,
+}
+
+impl InputName {
+    /// Names the input file for `identifier`, using `template` when there is one.
+    ///
+    /// An identifier that doesn't name a puzzle falls back to the usual file name, even
+    /// when a template is given.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTemplate`] if the template can't be expanded for the puzzle.
+    pub(crate) fn new(identifier: &str, template: Option<&str>) -> Result<Self, Error> {
+        let templated = match (template, parse_identifier(identifier)) {
+            (Some(template), Some(id)) => {
+                let expanded = expand_template(template, identifier, id).map_err(|message| {
+                    Error::InvalidTemplate {
+                        template: template.to_string(),
+                        message,
+                    }
+                })?;
+                Some(PathBuf::from(expanded))
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            identifier: identifier.to_string(),
+            templated,
+        })
+    }
+}
+
+/// Resolves the path of the input file for `name`.
+///
+/// Returns the explicit `input_path` if given, otherwise the first existing input file found
+/// by searching upward from the environment's current directory, trying the templated name
+/// before the usual one. The usual name is only searched for when it's specific to the
+/// puzzle, which it isn't in release builds. When no input file exists yet, the path in the
+/// default input directory is returned, or the current directory if there is none, using
+/// the templated name if there is one. The returned path is also where input gets saved.
+///
+/// # Errors
+///
+/// Returns [`Error::Io`] if the current directory cannot be determined.
+pub(crate) fn find_input_file_path(
+    name: &InputName,
+    input_path: Option<&Path>,
+    env: &dyn Environment,
+) -> Result<PathBuf, InternalError> {
+    if let Some(input_path) = input_path {
+        return Ok(input_path.to_path_buf());
+    }
+
+    let file_name = PathBuf::from(input_file_name(&name.identifier));
+    let current_dir = env.current_dir().map_err(Error::Io)?;
+
+    let searched_file_name = is_puzzle_specific().then_some(&file_name);
+    let found = name
+        .templated
+        .iter()
+        .chain(searched_file_name)
+        .find_map(|file_name| search_upward(&current_dir, file_name));
+    if let Some(found) = found {
+        return Ok(found);
+    }
+
+    let file_name = name.templated.as_ref().unwrap_or(&file_name);
+    let input_file_path = match env.default_input_dir() {
+        Some(input_dir) => input_dir.join(file_name),
+        None => current_dir.join(file_name),
     };
 
     Ok(input_file_path)
 }
 
+/// Whether the name of the input file from [`input_file_name`] is specific to the puzzle.
+fn is_puzzle_specific() -> bool {
+    cfg!(debug_assertions)
+}
+
+/// Gets the name of the input file for `identifier`.
+fn input_file_name(identifier: &str) -> String {
+    if cfg!(debug_assertions) {
+        format!("{identifier}.txt")
+    } else {
+        "input.txt".to_string()
+    }
+}
+
+/// Searches `start_dir` and its parents for `file_name`, returning the first hit.
+///
+/// Each directory is checked for the file itself, then for the file in an `input`
+/// subdirectory. The search stops after the repository root (identified by a `.git`
+/// directory) or after [`MAX_SEARCH_DEPTH`] parents, whichever comes first.
+fn search_upward(start_dir: &Path, file_name: &Path) -> Option<PathBuf> {
+    for dir in start_dir
+        .ancestors()
+        .take(MAX_SEARCH_DEPTH.saturating_add(1))
+    {
+        let candidates = [dir.join(file_name), dir.join("input").join(file_name)];
+
+        if let Some(found) = candidates.into_iter().find(|path| path.is_file()) {
+            return Some(found);
+        }
+
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+
+    None
+}
+
 /// Finds the working directory containing puzzle input files.
 ///
 /// In debug builds, this function traverses up from `CARGO_MANIFEST_DIR` to find the
@@ -54,7 +236,7 @@ pub(crate) fn find_input_file_path(identifier: &str) -> Result<PathBuf, Internal
 /// Returns [`Error::NotFound`] if no `.git` directory is found or if the `input`
 /// subdirectory doesn't exist in the repository root.
 #[cfg(debug_assertions)]
-fn find_working_dir() -> Result<PathBuf, Error> {
+pub(crate) fn find_working_dir() -> Result<PathBuf, Error> {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR")
         .map(PathBuf::from)
         .map_err(Error::Var)?;
@@ -72,7 +254,7 @@ fn find_working_dir() -> Result<PathBuf, Error> {
 /// Returns [`Error::Io`] if the current executable path cannot be determined.
 /// Returns [`Error::NotFound`] if the executable has no parent directory.
 #[cfg(not(debug_assertions))]
-fn find_working_dir() -> Result<PathBuf, Error> {
+pub(crate) fn find_working_dir() -> Result<PathBuf, Error> {
     let exe_path = env::current_exe().map_err(Error::Io)?;
 
     if let Some(parent) = exe_path.parent() {
@@ -108,9 +290,53 @@ fn find_working_dir_from(start_dir: PathBuf) -> Result<PathBuf, Error> {
 #[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
 mod tests {
     use super::*;
+    use crate::environment::RealEnvironment;
     use std::fs;
     use tempfile::TempDir;
 
+    #[test]
+    fn identifier_strips_platform_extension() {
+        assert_eq!(derive_identifier("day07.exe"), Some("day07".to_string()));
+        assert_eq!(derive_identifier("day07.bin"), Some("day07".to_string()));
+    }
+
+    #[test]
+    fn identifier_strips_cargo_hash() {
+        assert_eq!(
+            derive_identifier("day07-5f3a9c1b2d4e6f80"),
+            Some("day07".to_string())
+        );
+        assert_eq!(
+            derive_identifier("day07-5f3a9c1b2d4e6f80.exe"),
+            Some("day07".to_string())
+        );
+    }
+
+    #[test]
+    fn identifier_without_hash_is_kept() {
+        assert_eq!(
+            derive_identifier("aoc_2015_day01"),
+            Some("aoc_2015_day01".to_string())
+        );
+        // Not 16 hex digits, so not a hash
+        assert_eq!(
+            derive_identifier("aoc-day07-part2"),
+            Some("aoc-day07-part2".to_string())
+        );
+        assert_eq!(
+            derive_identifier("day07-5f3a9c1b2d4e6f8"),
+            Some("day07-5f3a9c1b2d4e6f8".to_string())
+        );
+    }
+
+    #[test]
+    fn garbage_identifier_is_an_error() {
+        assert_eq!(derive_identifier("a b c"), None);
+        assert_eq!(derive_identifier(".exe"), None);
+        assert_eq!(derive_identifier("-5f3a9c1b2d4e6f80"), None);
+    }
+
+    #[cfg(debug_assertions)]
     #[test]
     fn find_input_from_dir_returns_input_directory() {
         // Create a temporary directory structure with .git and input
@@ -126,6 +352,7 @@ mod tests {
         assert!(result.unwrap().ends_with("input"));
     }
 
+    #[cfg(debug_assertions)]
     #[test]
     fn find_input_from_dir_traverses_to_git_root() {
         // Create a temporary directory structure to test traversal
@@ -142,6 +369,7 @@ mod tests {
         assert_eq!(result.unwrap(), input_dir);
     }
 
+    #[cfg(debug_assertions)]
     #[test]
     fn find_input_from_dir_returns_not_found_without_input_dir() {
         // Create a temporary directory with .git but no input
@@ -153,4 +381,138 @@ mod tests {
         let result = find_working_dir_from(temp_dir.path().to_path_buf());
         assert!(matches!(result, Err(Error::NotFound)));
     }
+
+    #[test]
+    fn search_upward_prefers_current_dir_over_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let nested_dir = temp_dir.path().join("nested");
+        fs::create_dir(&nested_dir).unwrap();
+        fs::write(temp_dir.path().join("day01.txt"), "parent").unwrap();
+        fs::write(nested_dir.join("day01.txt"), "current").unwrap();
+
+        let result = search_upward(&nested_dir, Path::new("day01.txt"));
+        assert_eq!(result.unwrap(), nested_dir.join("day01.txt"));
+    }
+
+    #[test]
+    fn search_upward_finds_file_in_grandparent() {
+        let temp_dir = TempDir::new().unwrap();
+        let nested_dir = temp_dir.path().join("nested").join("deeply");
+        fs::create_dir_all(&nested_dir).unwrap();
+        fs::write(temp_dir.path().join("day01.txt"), "grandparent").unwrap();
+
+        let result = search_upward(&nested_dir, Path::new("day01.txt"));
+        assert_eq!(result.unwrap(), temp_dir.path().join("day01.txt"));
+    }
+
+    #[test]
+    fn search_upward_respects_depth_bound() {
+        let temp_dir = TempDir::new().unwrap();
+        let nested_dir = (0..10).fold(temp_dir.path().to_path_buf(), |dir, level| {
+            dir.join(format!("level{level}"))
+        });
+        fs::create_dir_all(&nested_dir).unwrap();
+        fs::write(temp_dir.path().join("day01.txt"), "too far up").unwrap();
+
+        let result = search_upward(&nested_dir, Path::new("day01.txt"));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn search_upward_finds_file_in_input_subdirectory() {
+        let temp_dir = TempDir::new().unwrap();
+        let input_dir = temp_dir.path().join("input");
+        let nested_dir = temp_dir.path().join("nested");
+        fs::create_dir(&input_dir).unwrap();
+        fs::create_dir(&nested_dir).unwrap();
+        fs::write(input_dir.join("day01.txt"), "input").unwrap();
+
+        let result = search_upward(&nested_dir, Path::new("day01.txt"));
+        assert_eq!(result.unwrap(), input_dir.join("day01.txt"));
+    }
+
+    #[test]
+    fn search_upward_stops_at_git_root() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo_dir = temp_dir.path().join("repo");
+        fs::create_dir_all(repo_dir.join(".git")).unwrap();
+        fs::write(temp_dir.path().join("day01.txt"), "outside repo").unwrap();
+
+        let result = search_upward(&repo_dir, Path::new("day01.txt"));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn find_input_file_path_prefers_explicit_path() {
+        let explicit = Path::new("somewhere/else.txt");
+        let name = InputName::new("day01", None).unwrap();
+        let result = find_input_file_path(&name, Some(explicit), &RealEnvironment);
+        assert_eq!(result.unwrap(), explicit);
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn release_build_does_not_search_parents_for_generic_name() {
+        struct InDir(PathBuf);
+
+        impl Environment for InDir {
+            fn current_exe(&self) -> std::io::Result<PathBuf> {
+                Ok(self.0.join("day01"))
+            }
+            fn args(&self) -> Vec<String> {
+                Vec::new()
+            }
+            fn var(&self, _key: &str) -> Option<String> {
+                None
+            }
+            fn current_dir(&self) -> std::io::Result<PathBuf> {
+                Ok(self.0.clone())
+            }
+            fn default_input_dir(&self) -> Option<PathBuf> {
+                None
+            }
+            fn read_stdin_line(&self, _buf: &mut String) -> std::io::Result<usize> {
+                Ok(0)
+            }
+            fn stdin_is_terminal(&self) -> bool {
+                false
+            }
+            fn confirm(&self, _prompt: &str) -> bool {
+                false
+            }
+            fn stdout(&self) -> Box<dyn std::io::Write + '_> {
+                Box::new(std::io::sink())
+            }
+            fn stderr(&self) -> Box<dyn std::io::Write + Send> {
+                Box::new(std::io::sink())
+            }
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let nested_dir = temp_dir.path().join("day02");
+        fs::create_dir(&nested_dir).unwrap();
+        fs::write(temp_dir.path().join("input.txt"), "another puzzle").unwrap();
+
+        let name = InputName::new("day01", None).unwrap();
+        let result = find_input_file_path(&name, None, &InDir(nested_dir.clone()));
+        assert_eq!(result.unwrap(), nested_dir.join("input.txt"));
+    }
+
+    #[test]
+    fn input_name_expands_template_for_puzzle_identifiers() {
+        let name = InputName::new("aoc2015_day07", Some("inputs/{year}/{day:02}.txt")).unwrap();
+        assert_eq!(name.templated, Some(PathBuf::from("inputs/2015/07.txt")));
+    }
+
+    #[test]
+    fn input_name_falls_back_for_unrecognized_identifiers() {
+        let name = InputName::new("solver", Some("inputs/{year}/{day:02}.txt")).unwrap();
+        assert_eq!(name.templated, None);
+    }
+
+    #[test]
+    fn input_name_without_year_for_template_is_an_error() {
+        let result = InputName::new("day07", Some("inputs/{year}/{day:02}.txt"));
+        assert!(matches!(result, Err(Error::InvalidTemplate { .. })));
+    }
 }
@@ -0,0 +1,219 @@
+//! Puzzle years and days parsed from identifiers.
+//!
+//! Identifiers like `aoc2015_day07` or `y2015d07` say which puzzle they belong to. When
+//! [`INPUT_TEMPLATE_VAR`] holds a path template, like `inputs/{year}/{day:02}.txt`, the
+//! parsed [`PuzzleId`] fills it in to name the input file, so inputs can be organized by
+//! year and day instead of by executable name.
+
+use crate::environment::Environment;
+use std::fmt::Write as _;
+
+/// The environment variable holding the input file path template.
+pub(crate) const INPUT_TEMPLATE_VAR: &str = "AOC_INPUT_TEMPLATE";
+
+/// The year and day of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PuzzleId {
+    /// The year of the event, if the identifier names one.
+    pub year: Option<u16>,
+    /// The day of the puzzle, from 1 to 25.
+    pub day: u8,
+}
+
+/// Parses the puzzle year and day from an identifier.
+///
+/// Recognizes, in any case:
+///
+/// - `day07`, with no year
+/// - `aoc2015_day07`, also with `-` or `_` between the parts, like `aoc_2015_day07`
+/// - `y2015d07`
+///
+/// Days can have one or two digits, and must be from 1 to 25. Years have four digits.
+///
+/// ```
+/// use input_reader::{PuzzleId, parse_identifier};
+///
+/// assert_eq!(
+///     parse_identifier("aoc_2015_day07"),
+///     Some(PuzzleId { year: Some(2015), day: 7 })
+/// );
+/// assert_eq!(parse_identifier("solver"), None);
+/// ```
+pub fn parse_identifier(identifier: &str) -> Option<PuzzleId> {
+    let identifier = identifier.to_ascii_lowercase();
+    parse_short_form(&identifier).or_else(|| parse_long_form(&identifier))
+}
+
+/// Parses the `y2015d07` form.
+fn parse_short_form(identifier: &str) -> Option<PuzzleId> {
+    let (year, day) = identifier.strip_prefix('y')?.split_once('d')?;
+
+    Some(PuzzleId {
+        year: Some(parse_year(year)?),
+        day: parse_day(day)?,
+    })
+}
+
+/// Parses the `day07` and `aoc2015_day07` forms.
+fn parse_long_form(identifier: &str) -> Option<PuzzleId> {
+    let (prefix, day) = identifier.rsplit_once("day")?;
+    let day = parse_day(day)?;
+
+    let prefix = prefix.trim_end_matches(['_', '-']);
+    if prefix.is_empty() {
+        return Some(PuzzleId { year: None, day });
+    }
+
+    let year = prefix.strip_prefix("aoc")?.trim_start_matches(['_', '-']);
+    Some(PuzzleId {
+        year: Some(parse_year(year)?),
+        day,
+    })
+}
+
+fn parse_year(year: &str) -> Option<u16> {
+    if year.len() != 4 || !year.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    year.parse().ok()
+}
+
+fn parse_day(day: &str) -> Option<u8> {
+    if !(1..=2).contains(&day.len()) || !day.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    day.parse().ok().filter(|day| (1..=25).contains(day))
+}
+
+/// Gets the input file path template from the environment, if one is configured.
+pub(crate) fn input_template_from_env(env: &dyn Environment) -> Option<String> {
+    env.var(INPUT_TEMPLATE_VAR)
+        .filter(|template| !template.trim().is_empty())
+}
+
+/// Fills in the placeholders of `template` for the puzzle `id` named `identifier`.
+///
+/// The placeholders are `{year}`, `{day}`, `{day:02}` for a zero-padded day, and
+/// `{identifier}`.
+///
+/// # Errors
+///
+/// Returns a message if a placeholder is unknown or unclosed, or `{year}` is used but
+/// `id` has no year.
+pub(crate) fn expand_template(
+    template: &str,
+    identifier: &str,
+    id: PuzzleId,
+) -> Result<String, String> {
+    let mut expanded = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some((before, after)) = rest.split_once('{') {
+        expanded.push_str(before);
+
+        let (placeholder, after) = after
+            .split_once('}')
+            .ok_or_else(|| "unclosed placeholder".to_string())?;
+
+        match placeholder {
+            "year" => {
+                let year = id.year.ok_or_else(|| {
+                    format!("{{year}} is used, but the identifier {identifier:?} has no year")
+                })?;
+                let _ = write!(expanded, "{year}");
+            }
+            "day" => {
+                let _ = write!(expanded, "{}", id.day);
+            }
+            "day:02" => {
+                let _ = write!(expanded, "{:02}", id.day);
+            }
+            "identifier" => expanded.push_str(identifier),
+            _ => return Err(format!("unknown placeholder {{{placeholder}}}")),
+        }
+
+        rest = after;
+    }
+
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+mod tests {
+    use super::*;
+
+    fn id(year: Option<u16>, day: u8) -> PuzzleId {
+        PuzzleId { year, day }
+    }
+
+    #[test]
+    fn parses_day_only() {
+        assert_eq!(parse_identifier("day07"), Some(id(None, 7)));
+        assert_eq!(parse_identifier("Day7"), Some(id(None, 7)));
+    }
+
+    #[test]
+    fn parses_aoc_year_and_day() {
+        assert_eq!(parse_identifier("aoc2015_day07"), Some(id(Some(2015), 7)));
+        assert_eq!(parse_identifier("aoc_2016_day25"), Some(id(Some(2016), 25)));
+        assert_eq!(parse_identifier("aoc-2015-day1"), Some(id(Some(2015), 1)));
+    }
+
+    #[test]
+    fn parses_short_form() {
+        assert_eq!(parse_identifier("y2015d07"), Some(id(Some(2015), 7)));
+        assert_eq!(parse_identifier("Y2016D3"), Some(id(Some(2016), 3)));
+    }
+
+    #[test]
+    fn unrecognized_identifiers() {
+        for identifier in [
+            "solver",
+            "day",
+            "day00",
+            "day26",
+            "day007",
+            "aoc15_day07",
+            "my2015_day07",
+            "y2015",
+            "y15d07",
+        ] {
+            assert_eq!(parse_identifier(identifier), None, "{identifier}");
+        }
+    }
+
+    #[test]
+    fn expands_placeholders_with_zero_padding() {
+        let expanded = expand_template(
+            "inputs/{year}/{day:02}-{day}-{identifier}.txt",
+            "aoc2015_day07",
+            id(Some(2015), 7),
+        )
+        .unwrap();
+
+        assert_eq!(expanded, "inputs/2015/07-7-aoc2015_day07.txt");
+    }
+
+    #[test]
+    fn year_placeholder_needs_a_year() {
+        let err = expand_template("inputs/{year}/{day:02}.txt", "day07", id(None, 7)).unwrap_err();
+        assert_eq!(
+            err,
+            "{year} is used, but the identifier \"day07\" has no year"
+        );
+    }
+
+    #[test]
+    fn bad_placeholders_are_errors() {
+        assert_eq!(
+            expand_template("{month}.txt", "day07", id(None, 7)).unwrap_err(),
+            "unknown placeholder {month}"
+        );
+        assert_eq!(
+            expand_template("{day.txt", "day07", id(None, 7)).unwrap_err(),
+            "unclosed placeholder"
+        );
+    }
+}
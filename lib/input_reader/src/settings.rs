@@ -0,0 +1,62 @@
+//! Settings read from environment variables.
+
+use crate::environment::Environment;
+use crate::fetch::fetch_command_from_env;
+use crate::puzzle_id::input_template_from_env;
+use crate::warnings::DEFAULT_LONG_LINE_THRESHOLD;
+
+/// The environment variable that, when set, keeps input warnings quiet.
+pub(crate) const QUIET_VAR: &str = "AOC_QUIET";
+
+/// The environment variable that, when set, turns on warnings about leading whitespace.
+pub(crate) const WARN_LEADING_VAR: &str = "AOC_WARN_LEADING";
+
+/// The environment variable overriding the long line warning threshold.
+pub(crate) const LONG_LINE_VAR: &str = "AOC_LONG_LINE";
+
+/// Settings that apply to every puzzle, as opposed to per-run flags.
+#[derive(Debug)]
+pub(crate) struct Settings {
+    /// The command template used to fetch missing input, if any.
+    pub fetch_command: Option<String>,
+    /// The template naming the input file by puzzle year and day, if any.
+    pub input_template: Option<String>,
+    /// Whether input warnings are kept quiet. They are still returned.
+    pub quiet: bool,
+    /// Whether lines starting with whitespace produce a warning.
+    pub warn_leading_whitespace: bool,
+    /// Lines longer than this many characters produce a warning.
+    pub long_line_threshold: usize,
+}
+
+impl Settings {
+    /// Reads the settings from the environment.
+    ///
+    /// Unset or invalid values fall back to the defaults.
+    pub(crate) fn from_env(env: &dyn Environment) -> Self {
+        let long_line_threshold = env
+            .var(LONG_LINE_VAR)
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or(DEFAULT_LONG_LINE_THRESHOLD);
+
+        Self {
+            fetch_command: fetch_command_from_env(env),
+            input_template: input_template_from_env(env),
+            quiet: env.var(QUIET_VAR).is_some(),
+            warn_leading_whitespace: env.var(WARN_LEADING_VAR).is_some(),
+            long_line_threshold,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            fetch_command: None,
+            input_template: None,
+            quiet: false,
+            warn_leading_whitespace: false,
+            long_line_threshold: DEFAULT_LONG_LINE_THRESHOLD,
+        }
+    }
+}
@@ -1,109 +1,484 @@
-use std::io::{self, Write};
+//! User-facing notifications.
+//!
+//! Notifications are described by [`Event`] and written through a [`Terminal`], which
+//! renders them as human-readable messages or, for scripts, as single-line JSON objects
+//! on stderr.
 
-#[expect(clippy::print_stdout, reason = "intentional user-facing output")]
-pub(crate) fn print_help(identifier: &str) {
-    println!(
+use crate::{
+    args::{FLAGS, FlagSpec, FlagValue},
+    environment::Environment,
+    types::OutputFormat,
+    warnings::InputWarning,
+};
+use std::{fmt::Write as _, io::Write, path::Path};
+
+/// The width the help text is wrapped to.
+const HELP_WIDTH: usize = 88;
+
+/// Writes the usage text to `out`.
+///
+/// The options are listed from [`FLAGS`], with their descriptions aligned and wrapped.
+/// Write errors are ignored, as there's nowhere left to report them.
+pub(crate) fn print_help(out: &mut impl Write, identifier: &str) {
+    let usages: Vec<String> = FLAGS.iter().map(flag_usage).collect();
+    let column = usages
+        .iter()
+        .map(String::len)
+        .max()
+        .unwrap_or_default()
+        .saturating_add(2);
+    let description_width = HELP_WIDTH.saturating_sub(column).saturating_sub(4);
+
+    let mut options = String::new();
+    for (spec, usage) in FLAGS.iter().zip(&usages) {
+        for (index, line) in wrap(spec.description, description_width).iter().enumerate() {
+            let usage = if index == 0 { usage.as_str() } else { "" };
+            let _ = writeln!(options, "    {usage:column$}{line}");
+        }
+    }
+
+    let _ = writeln!(
+        out,
         "\
 USAGE: {identifier} [OPTIONS] [DATA...]
 
 OPTIONS:
-    -h, --help              Print this help message
-    -i, --input <METHOD>    Set input method (no value defaults to file)
-                            Methods: file, args, stdin
-    -s, --save              Save input to file for future runs
-    -f, --force             Force operations without prompts
-
+{options}
 ARGS:
-    [DATA...]               Input data (when using args method)
+    {data:column$}Input data (when using args method)
 
 NOTES:
     - Short flags can be grouped: -hsf, -sfi stdin
     - Use -- to treat remaining arguments as data
-    - Unknown flags are treated as data"
+    - Unknown --KEY VALUE and --KEY=VALUE pairs are passed on as solution options
+    - Other unknown flags are errors, negative numbers like -5 are data
+    - Set AOC_FETCH_CMD to a command fetching missing input, {{identifier}} and
+      {{path}} are replaced with the puzzle name and the input file path
+    - Set AOC_INPUT_TEMPLATE to name input files by puzzle, like
+      inputs/{{year}}/{{day:02}}.txt, for identifiers like aoc2015_day07 or y2015d07
+    - Set AOC_QUIET to hide warnings about the input, like trailing whitespace
+    - Set AOC_WARN_LEADING to also warn about lines starting with whitespace
+    - Set AOC_LONG_LINE to the length above which a line is unusually long
+    - JSON notifications go to stderr, one object per line
+    - JSON output never prompts, use --force to overwrite saved input",
+        data = "[DATA...]"
     );
 }
 
-#[expect(clippy::print_stdout, reason = "intentional user-facing output")]
-pub(crate) fn print_request_for_input() {
-    println!(
-        "\
-Please provide the input, ending with two blank lines:"
-    );
-}
+/// The flag column of the help for `spec`, like `-i, --input [METHOD]`.
+fn flag_usage(spec: &FlagSpec) -> String {
+    let mut usage = match spec.short {
+        Some(short) => format!("-{short}, --{}", spec.long),
+        None => format!("    --{}", spec.long),
+    };
 
-#[expect(clippy::print_stdout, reason = "intentional user-facing output")]
-pub(crate) fn print_no_input() {
-    println!(
-        "\
-No input data found. Exiting."
-    );
+    match spec.value {
+        FlagValue::None => {}
+        FlagValue::Optional(name) => {
+            let _ = write!(usage, " [{name}]");
+        }
+        FlagValue::Required(name) => {
+            let _ = write!(usage, " <{name}>");
+        }
+    }
+
+    usage
 }
 
-/// Prints a confirmation message that input was saved to a file.
-#[expect(clippy::print_stdout, reason = "intentional user-facing output")]
-pub(crate) fn print_input_saved(identifier: &str) {
-    if cfg!(debug_assertions) {
-        println!(
-            "\
-Saved input to: ./input/{identifier}.txt"
-        );
-    } else {
-        println!(
-            "\
-Saved input."
-        );
+/// Wraps each line of `text` to `width` characters, breaking between words.
+///
+/// Words longer than `width` are kept whole on their own line.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() && line.len().saturating_add(word.len()) >= width {
+                lines.push(std::mem::take(&mut line));
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+
+        lines.push(line);
     }
+
+    lines
 }
 
-#[expect(clippy::print_stdout, reason = "intentional user-facing output")]
-pub(crate) fn print_save_aborted() {
-    println!(
-        "\
-Save aborted."
-    );
+/// A notification for the user.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Event<'a> {
+    /// Input is about to be read from stdin.
+    AwaitingInput,
+    /// The puzzle identifier was derived from the executable name `exe_name`.
+    Identifier {
+        exe_name: &'a str,
+        identifier: &'a str,
+    },
+    /// Input is read from the file at `path`.
+    InputPath { path: &'a Path },
+    /// No input was found.
+    NoInput,
+    /// Input was saved to the file at `path`.
+    InputSaved { identifier: &'a str, path: &'a Path },
+    /// The user declined to overwrite an existing input file.
+    SaveAborted,
+    /// An existing input file was kept because prompting isn't possible.
+    OverwriteDeclined { path: &'a Path },
+    /// The input came from a file, so there's nothing to save.
+    NothingToSave,
+    /// A line read from a TTY may have been truncated.
+    TruncationWarning { line_len: usize },
+    /// Saving was refused because of potential truncation.
+    SaveRefused,
+    /// The fetch command failed to create the input file.
+    FetchFailed { reason: &'a str },
+    /// The clipboard couldn't be read.
+    ClipboardFailed { reason: &'a str },
+    /// The input may not be what the puzzle expects.
+    InputWarning { warning: &'a InputWarning },
 }
 
-#[expect(clippy::print_stdout, reason = "intentional user-facing output")]
-pub(crate) fn print_nothing_to_save() {
-    println!(
-        "\
-Nothing to save."
-    );
+/// The standard stream a text message is written to.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Stream {
+    Stdout,
+    Stderr,
 }
 
-#[expect(clippy::print_stderr, reason = "intentional warning output")]
-pub(crate) fn print_truncation_warning() {
-    eprintln!(
-        "\
-Warning: Input line may have been truncated (TTY buffer limit)."
-    );
+impl Event<'_> {
+    /// The name of the event in JSON output.
+    fn name(&self) -> &'static str {
+        match self {
+            Event::AwaitingInput => "awaiting_input",
+            Event::Identifier { .. } => "identifier",
+            Event::InputPath { .. } => "input_path",
+            Event::NoInput => "no_input",
+            Event::InputSaved { .. } => "input_saved",
+            Event::SaveAborted => "save_aborted",
+            Event::OverwriteDeclined { .. } => "overwrite_declined",
+            Event::NothingToSave => "nothing_to_save",
+            Event::TruncationWarning { .. } => "truncation_warning",
+            Event::SaveRefused => "save_refused",
+            Event::FetchFailed { .. } => "fetch_failed",
+            Event::ClipboardFailed { .. } => "clipboard_failed",
+            Event::InputWarning { .. } => "input_warning",
+        }
+    }
+
+    /// The human-readable message and the stream it's written to.
+    fn text(&self) -> (Stream, String) {
+        match self {
+            Event::AwaitingInput => (
+                Stream::Stdout,
+                "Please provide the input, ending with two blank lines:".to_string(),
+            ),
+            Event::Identifier {
+                exe_name,
+                identifier,
+            } => (
+                Stream::Stderr,
+                format!("Using identifier: {identifier} (from executable {exe_name})"),
+            ),
+            Event::InputPath { path } => (
+                Stream::Stderr,
+                format!("Using input file: {}", path.display()),
+            ),
+            Event::NoInput => (Stream::Stdout, "No input data found. Exiting.".to_string()),
+            Event::InputSaved { path, .. } => {
+                if cfg!(debug_assertions) {
+                    (
+                        Stream::Stdout,
+                        format!("Saved input to: {}", path.display()),
+                    )
+                } else {
+                    (Stream::Stdout, "Saved input.".to_string())
+                }
+            }
+            Event::SaveAborted => (Stream::Stdout, "Save aborted.".to_string()),
+            Event::OverwriteDeclined { .. } => (
+                Stream::Stdout,
+                "Input file already exists, use --force to overwrite it.".to_string(),
+            ),
+            Event::NothingToSave => (Stream::Stdout, "Nothing to save.".to_string()),
+            Event::TruncationWarning { .. } => (
+                Stream::Stderr,
+                "Warning: Input line may have been truncated (TTY buffer limit).".to_string(),
+            ),
+            Event::SaveRefused => (
+                Stream::Stderr,
+                "Warning: Save refused due to potential data truncation.".to_string(),
+            ),
+            Event::FetchFailed { reason } => (
+                Stream::Stderr,
+                format!("Warning: Could not fetch input: {reason}."),
+            ),
+            Event::ClipboardFailed { reason } => (
+                Stream::Stderr,
+                format!("Warning: Could not read the clipboard: {reason}."),
+            ),
+            Event::InputWarning { warning } => {
+                (Stream::Stderr, format!("Warning: Input {warning}."))
+            }
+        }
+    }
+
+    /// The event as a single-line JSON object.
+    fn json(&self) -> String {
+        let mut json = format!("{{\"event\":{}", json_string(self.name()));
+
+        match self {
+            Event::InputPath { path } | Event::OverwriteDeclined { path } => {
+                let path = path.display().to_string();
+                let _ = write!(json, ",\"path\":{}", json_string(&path));
+            }
+            Event::Identifier {
+                exe_name,
+                identifier,
+            } => {
+                let _ = write!(
+                    json,
+                    ",\"exe_name\":{},\"identifier\":{}",
+                    json_string(exe_name),
+                    json_string(identifier)
+                );
+            }
+            Event::InputSaved { identifier, path } => {
+                let path = path.display().to_string();
+                let _ = write!(
+                    json,
+                    ",\"identifier\":{},\"path\":{}",
+                    json_string(identifier),
+                    json_string(&path)
+                );
+            }
+            Event::TruncationWarning { line_len } => {
+                let _ = write!(json, ",\"line_len\":{line_len}");
+            }
+            Event::FetchFailed { reason } | Event::ClipboardFailed { reason } => {
+                let _ = write!(json, ",\"reason\":{}", json_string(reason));
+            }
+            Event::InputWarning { warning } => {
+                let _ = write!(
+                    json,
+                    ",\"kind\":{},\"line\":{},\"message\":{}",
+                    json_string(warning.kind()),
+                    warning.line(),
+                    json_string(&warning.to_string())
+                );
+            }
+            Event::AwaitingInput
+            | Event::NoInput
+            | Event::SaveAborted
+            | Event::NothingToSave
+            | Event::SaveRefused => {}
+        }
+
+        json.push('}');
+        json
+    }
 }
 
-#[expect(clippy::print_stderr, reason = "intentional warning output")]
-pub(crate) fn print_save_refused() {
-    eprintln!(
-        "\
-Warning: Save refused due to potential data truncation."
-    );
+/// Quotes and escapes a string for use in JSON.
+fn json_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len().saturating_add(2));
+    quoted.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(c));
+            }
+            c => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
 }
 
-/// Prompts the user for confirmation to overwrite an existing file.
+/// Writes notifications for the user in the chosen output format.
 ///
-/// Returns `true` if the user confirms (responds with 'y' or 'Y'),
-/// `false` otherwise.
-#[expect(clippy::print_stdout, reason = "intentional user-facing output")]
-pub(crate) fn prompt_overwrite_confirmation() -> bool {
-    println!(
-        "\
-Input file already exists. Overwrite? (y/N): "
-    );
-    io::stdout().flush().ok();
-
-    let mut response = String::new();
-    if io::stdin().read_line(&mut response).is_ok() {
-        let response = response.trim().to_lowercase();
-        response == "y" || response == "yes"
-    } else {
-        false
+/// The writers are usually the stdout and stderr of the [`Environment`], but can be
+/// replaced to capture the output.
+pub(crate) struct Terminal<O, E> {
+    format: OutputFormat,
+    stdout: O,
+    stderr: E,
+}
+
+impl<O: Write, E: Write> Terminal<O, E> {
+    pub(crate) fn with_writers(format: OutputFormat, stdout: O, stderr: E) -> Self {
+        Self {
+            format,
+            stdout,
+            stderr,
+        }
+    }
+
+    /// Writes a notification.
+    ///
+    /// Write errors are ignored, as there's nowhere left to report them.
+    pub(crate) fn notify(&mut self, event: &Event<'_>) {
+        let (stream, message) = match self.format {
+            OutputFormat::Text => event.text(),
+            OutputFormat::Json => (Stream::Stderr, event.json()),
+        };
+
+        let _ = match stream {
+            Stream::Stdout => writeln!(self.stdout, "{message}"),
+            Stream::Stderr => writeln!(self.stderr, "{message}"),
+        };
+    }
+
+    /// Asks the user, through `env`, for confirmation to overwrite an existing file.
+    ///
+    /// Returns `true` if the user confirms, `false` otherwise.
+    ///
+    /// JSON output can't be interactive, so the overwrite is declined with an
+    /// [`Event::OverwriteDeclined`] notification instead.
+    pub(crate) fn confirm_overwrite(&mut self, path: &Path, env: &dyn Environment) -> bool {
+        if self.format == OutputFormat::Json {
+            self.notify(&Event::OverwriteDeclined { path });
+            return false;
+        }
+
+        self.stdout.flush().ok();
+        env.confirm("Input file already exists. Overwrite? (y/N): ")
+    }
+
+    /// Consumes the terminal, returning its writers.
+    #[cfg(test)]
+    pub(crate) fn into_writers(self) -> (O, E) {
+        (self.stdout, self.stderr)
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+mod tests {
+    use super::*;
+
+    fn capture(format: OutputFormat, event: &Event<'_>) -> (String, String) {
+        let mut terminal = Terminal::with_writers(format, Vec::new(), Vec::new());
+        terminal.notify(event);
+
+        let (stdout, stderr) = terminal.into_writers();
+        (
+            String::from_utf8(stdout).unwrap(),
+            String::from_utf8(stderr).unwrap(),
+        )
+    }
+
+    #[test]
+    fn text_output_keeps_messages_on_their_streams() {
+        let (stdout, stderr) = capture(OutputFormat::Text, &Event::NoInput);
+        assert_eq!(stdout, "No input data found. Exiting.\n");
+        assert_eq!(stderr, "");
+
+        let (stdout, stderr) = capture(OutputFormat::Text, &Event::SaveRefused);
+        assert_eq!(stdout, "");
+        assert_eq!(
+            stderr,
+            "Warning: Save refused due to potential data truncation.\n"
+        );
+    }
+
+    #[test]
+    fn json_output_is_a_single_line_on_stderr() {
+        let event = Event::TruncationWarning { line_len: 4012 };
+        let (stdout, stderr) = capture(OutputFormat::Json, &event);
+
+        assert_eq!(stdout, "");
+        assert_eq!(
+            stderr,
+            "{\"event\":\"truncation_warning\",\"line_len\":4012}\n"
+        );
+    }
+
+    #[test]
+    fn json_output_escapes_strings() {
+        let event = Event::InputSaved {
+            identifier: "day\"07\"",
+            path: Path::new("C:\\input\\day07.txt"),
+        };
+        let (_, stderr) = capture(OutputFormat::Json, &event);
+
+        let json: serde_json::Value = serde_json::from_str(&stderr).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "event": "input_saved",
+                "identifier": "day\"07\"",
+                "path": "C:\\input\\day07.txt",
+            })
+        );
+    }
+
+    #[test]
+    fn json_string_escapes_control_characters() {
+        assert_eq!(json_string("a\tb\u{1}"), "\"a\\tb\\u0001\"");
+    }
+
+    #[test]
+    fn help_lists_every_flag_once() {
+        let mut out = Vec::new();
+        print_help(&mut out, "day07");
+        let help = String::from_utf8(out).unwrap();
+
+        let (_, options) = help.split_once("OPTIONS:").unwrap();
+        let (options, _) = options.split_once("ARGS:").unwrap();
+        let words: Vec<_> = options
+            .split_whitespace()
+            .map(|word| word.trim_end_matches(','))
+            .collect();
+
+        for spec in &FLAGS {
+            let long = format!("--{}", spec.long);
+            assert_eq!(
+                words.iter().filter(|&&word| word == long).count(),
+                1,
+                "{long}"
+            );
+
+            if let Some(short) = spec.short {
+                let short = format!("-{short}");
+                assert_eq!(
+                    words.iter().filter(|&&word| word == short).count(),
+                    1,
+                    "{short}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn help_lines_fit_the_width() {
+        let mut out = Vec::new();
+        print_help(&mut out, "day07");
+        let help = String::from_utf8(out).unwrap();
+
+        for line in help.lines() {
+            assert!(line.len() <= HELP_WIDTH, "{line:?} is too long");
+        }
+    }
+
+    #[test]
+    fn wrap_breaks_between_words() {
+        assert_eq!(
+            wrap("one two three four\nfive", 9),
+            vec!["one two", "three", "four", "five"]
+        );
+        assert_eq!(wrap("unbreakable", 4), vec!["unbreakable"]);
     }
 }
@@ -2,10 +2,13 @@
 //!
 //! This module contains all the public and internal types used throughout the library.
 
+use crate::args::ArgError;
+use crate::warnings::{InputInspector, InputWarning};
 use std::{
     env, error, fmt,
     fs::File,
-    io::{self, BufRead, BufReader, Seek},
+    io::{self, BufRead, BufReader, Read, Seek},
+    str::FromStr,
     string::ToString,
     vec::Vec,
 };
@@ -24,6 +27,17 @@ pub enum Error {
     Io(io::Error),
     /// An environment variable was not set or invalid.
     Var(env::VarError),
+    /// The command-line arguments were invalid.
+    Args(ArgError),
+    /// No puzzle identifier could be derived from the executable name.
+    InvalidIdentifier(String),
+    /// The input file path template in `AOC_INPUT_TEMPLATE` could not be expanded.
+    InvalidTemplate {
+        /// The template as it was configured.
+        template: String,
+        /// What is wrong with it.
+        message: String,
+    },
 }
 
 impl From<io::Error> for Error {
@@ -38,12 +52,26 @@ impl From<env::VarError> for Error {
     }
 }
 
+impl From<ArgError> for Error {
+    fn from(err: ArgError) -> Self {
+        Error::Args(err)
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::NotFound => write!(f, "path or directory not found"),
             Error::Io(err) => write!(f, "I/O error: {err}"),
             Error::Var(err) => write!(f, "environment variable error: {err}"),
+            Error::Args(err) => write!(f, "argument error: {err}"),
+            Error::InvalidIdentifier(exe_name) => write!(
+                f,
+                "cannot derive a puzzle identifier from the executable name {exe_name:?}"
+            ),
+            Error::InvalidTemplate { template, message } => {
+                write!(f, "invalid input template {template:?}: {message}")
+            }
         }
     }
 }
@@ -51,9 +79,10 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
-            Error::NotFound => None,
+            Error::NotFound | Error::InvalidIdentifier(_) | Error::InvalidTemplate { .. } => None,
             Error::Io(err) => Some(err),
             Error::Var(err) => Some(err),
+            Error::Args(err) => Some(err),
         }
     }
 }
@@ -98,6 +127,57 @@ impl fmt::Display for InternalError {
     }
 }
 
+// =============================================================================
+// ParseLinesError
+// =============================================================================
+
+/// Errors returned by [`Input::parse_lines`] and [`Input::parse_lines_strict`].
+#[derive(Debug)]
+pub enum ParseLinesError {
+    /// An I/O error occurred while reading a line.
+    Io(io::Error),
+    /// A line could not be parsed.
+    Parse {
+        /// The 1-based number of the line.
+        line_number: usize,
+        /// The line as it was read.
+        line: String,
+        /// The message of the underlying parse error.
+        message: String,
+    },
+}
+
+impl From<io::Error> for ParseLinesError {
+    fn from(err: io::Error) -> Self {
+        ParseLinesError::Io(err)
+    }
+}
+
+impl fmt::Display for ParseLinesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLinesError::Io(err) => write!(f, "I/O error: {err}"),
+            ParseLinesError::Parse {
+                line_number,
+                line,
+                message,
+            } => write!(
+                f,
+                "failed to parse line {line_number} ({line:?}): {message}"
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseLinesError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ParseLinesError::Io(err) => Some(err),
+            ParseLinesError::Parse { .. } => None,
+        }
+    }
+}
+
 // =============================================================================
 // InputMethod
 // =============================================================================
@@ -112,8 +192,29 @@ pub(crate) enum InputMethod {
     File,
     /// Read from command-line arguments.
     Args,
-    /// Read from standard input.
+    /// Read from standard input, connected to a terminal.
     Stdin,
+    /// Read from standard input, piped from another program or a file.
+    ///
+    /// Never requested with `--input`, it's how [`InputMethod::Stdin`] turns out when
+    /// stdin isn't a terminal.
+    Pipe,
+    /// Read from the system clipboard (`--clipboard`).
+    Clipboard,
+}
+
+// =============================================================================
+// OutputFormat
+// =============================================================================
+
+/// The format of user-facing notifications.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub(crate) enum OutputFormat {
+    /// Human-readable messages.
+    #[default]
+    Text,
+    /// Single-line JSON objects on stderr, for scripts.
+    Json,
 }
 
 // =============================================================================
@@ -126,10 +227,10 @@ pub(crate) enum InputMethod {
 /// command-line arguments, or standard input. It should be consumed using
 /// either [`lines()`](Input::lines) or [`chars()`](Input::chars).
 #[derive(Debug)]
-#[must_use = "Input should be consumed with lines() or chars()"]
+#[must_use = "Input should be consumed with lines(), chars() or parse_lines()"]
 pub enum Input {
     /// Input from a file, read using a buffered reader.
-    File(BufReader<File>),
+    File(FileReader),
     /// Input from memory (args or stdin), stored as lines.
     Memory(Vec<String>),
 }
@@ -156,6 +257,97 @@ impl Input {
         }
     }
 
+    /// Returns an iterator over the lines of input that aren't blank.
+    ///
+    /// Lines that are empty or only whitespace are skipped, other lines are returned
+    /// as they are. Read errors are always passed on.
+    pub fn non_blank_lines(self) -> Box<dyn Iterator<Item = io::Result<String>>> {
+        Box::new(
+            self.lines()
+                .filter(|line| !line.as_ref().is_ok_and(|line| line.trim().is_empty())),
+        )
+    }
+
+    /// Collects the non-blank lines of input, with surrounding whitespace trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading a line fails.
+    pub fn trimmed_lines(self) -> io::Result<Vec<String>> {
+        self.non_blank_lines()
+            .map(|line| line.map(|line| line.trim().to_string()))
+            .collect()
+    }
+
+    /// Parses each non-blank line of input into a `T`.
+    ///
+    /// Surrounding whitespace is trimmed before parsing. Lines are parsed as they are
+    /// read, so file input never holds more than one line in memory at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseLinesError::Parse`] for the first line that fails to parse, and
+    /// [`ParseLinesError::Io`] if reading a line fails.
+    pub fn parse_lines<T>(self) -> Result<Vec<T>, ParseLinesError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.parse_lines_with(true)
+    }
+
+    /// Parses every line of input into a `T`, treating blank lines as errors.
+    ///
+    /// Works like [`parse_lines()`](Input::parse_lines), except blank lines are reported
+    /// instead of skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseLinesError::Parse`] for the first line that is blank or fails to
+    /// parse, and [`ParseLinesError::Io`] if reading a line fails.
+    pub fn parse_lines_strict<T>(self) -> Result<Vec<T>, ParseLinesError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.parse_lines_with(false)
+    }
+
+    fn parse_lines_with<T>(self, skip_blanks: bool) -> Result<Vec<T>, ParseLinesError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let mut values = Vec::new();
+
+        for (index, line) in self.lines().enumerate() {
+            let line = line?;
+            let trimmed = line.trim();
+
+            let parsed = if trimmed.is_empty() {
+                if skip_blanks {
+                    continue;
+                }
+                Err("blank line".to_string())
+            } else {
+                trimmed.parse().map_err(|err: T::Err| err.to_string())
+            };
+
+            match parsed {
+                Ok(value) => values.push(value),
+                Err(message) => {
+                    return Err(ParseLinesError::Parse {
+                        line_number: index.saturating_add(1),
+                        line,
+                        message,
+                    });
+                }
+            }
+        }
+
+        Ok(values)
+    }
+
     /// Returns an iterator over the characters of input.
     ///
     /// # Implementation Notes
@@ -260,6 +452,131 @@ impl From<Vec<String>> for Input {
     }
 }
 
+// =============================================================================
+// FileReader
+// =============================================================================
+
+/// A buffered reader of an input file, which can check each line for problems as it's
+/// read.
+///
+/// The warnings are reported once the end of the file is reached, so the file is only
+/// read once, by whoever consumes the [`Input`]. Reading only part of the file reports
+/// nothing.
+pub struct FileReader {
+    reader: BufReader<File>,
+    inspection: Option<Inspection>,
+}
+
+/// The inspection of a file that is still being read.
+struct Inspection {
+    inspector: InputInspector,
+    /// The start of a line whose end hasn't been read yet.
+    partial_line: Vec<u8>,
+    /// Gets the warnings once the end of the file is reached.
+    report: Box<dyn FnOnce(Vec<InputWarning>) + Send>,
+}
+
+impl FileReader {
+    /// Reads from `reader`, without checking the lines.
+    pub fn new(reader: BufReader<File>) -> Self {
+        Self {
+            reader,
+            inspection: None,
+        }
+    }
+
+    /// Checks each line with `inspector` as it's read, passing the warnings to `report`
+    /// at the end of the file.
+    pub(crate) fn inspect_with(
+        &mut self,
+        inspector: InputInspector,
+        report: impl FnOnce(Vec<InputWarning>) + Send + 'static,
+    ) {
+        self.inspection = Some(Inspection {
+            inspector,
+            partial_line: Vec::new(),
+            report: Box::new(report),
+        });
+    }
+
+    /// The file being read.
+    pub fn get_ref(&self) -> &File {
+        self.reader.get_ref()
+    }
+}
+
+impl Inspection {
+    /// Inspects the lines completed by `bytes`, keeping the start of an unfinished line.
+    fn consume(&mut self, bytes: &[u8]) {
+        for piece in bytes.split_inclusive(|&byte| byte == b'\n') {
+            self.partial_line.extend_from_slice(piece);
+
+            if piece.ends_with(b"\n") {
+                self.inspector
+                    .inspect(&String::from_utf8_lossy(&self.partial_line));
+                self.partial_line.clear();
+            }
+        }
+    }
+
+    /// Inspects the last line, if it had no line ending, and reports the warnings.
+    fn finish(mut self) {
+        if !self.partial_line.is_empty() {
+            self.inspector
+                .inspect(&String::from_utf8_lossy(&self.partial_line));
+        }
+
+        (self.report)(self.inspector.finish());
+    }
+}
+
+impl From<BufReader<File>> for FileReader {
+    fn from(reader: BufReader<File>) -> Self {
+        Self::new(reader)
+    }
+}
+
+impl fmt::Debug for FileReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileReader")
+            .field("reader", &self.reader)
+            .field("inspected", &self.inspection.is_some())
+            .finish()
+    }
+}
+
+impl Read for FileReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Go through the buffer, so every byte read is inspected in `consume`
+        let read = self.fill_buf()?.read(buf)?;
+        self.consume(read);
+        Ok(read)
+    }
+}
+
+impl BufRead for FileReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let buf = self.reader.fill_buf()?;
+
+        if buf.is_empty()
+            && let Some(inspection) = self.inspection.take()
+        {
+            inspection.finish();
+        }
+
+        Ok(buf)
+    }
+
+    fn consume(&mut self, amount: usize) {
+        if let Some(inspection) = &mut self.inspection {
+            let consumed = self.reader.buffer().get(..amount).unwrap_or_default();
+            inspection.consume(consumed);
+        }
+
+        self.reader.consume(amount);
+    }
+}
+
 // =============================================================================
 // Outcome
 // =============================================================================
@@ -274,6 +591,8 @@ pub enum Outcome {
     Exit,
     /// Input is ready for processing.
     Continue(Input),
+    /// Input was loaded for a dry run (`--dry-run`), the caller shouldn't solve it.
+    DryRun(Input),
 }
 
 impl From<Input> for Outcome {
@@ -317,12 +636,105 @@ mod tests {
 
         let file = std::fs::File::open(temp_file.path()).unwrap();
         let reader = BufReader::new(file);
-        let input = Input::File(reader);
+        let input = Input::File(reader.into());
 
         let lines: Vec<String> = input.lines().map(|r| r.unwrap()).collect();
         assert_eq!(lines, vec!["file line 1", "file line 2"]);
     }
 
+    // Input::non_blank_lines() and Input::trimmed_lines() tests
+
+    #[test]
+    fn non_blank_lines_skips_whitespace_lines() {
+        let input = Input::from(vec!["", " a ", "  ", "\t", "b"]);
+        let lines: Vec<String> = input.non_blank_lines().map(|r| r.unwrap()).collect();
+        assert_eq!(lines, vec![" a ", "b"]);
+    }
+
+    #[test]
+    fn non_blank_lines_streams_file_input() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file).unwrap();
+        writeln!(temp_file, "first").unwrap();
+        writeln!(temp_file, "   ").unwrap();
+        writeln!(temp_file, "second").unwrap();
+
+        let file = std::fs::File::open(temp_file.path()).unwrap();
+        let input = Input::File(BufReader::new(file).into());
+
+        let lines: Vec<String> = input.non_blank_lines().map(|r| r.unwrap()).collect();
+        assert_eq!(lines, vec!["first", "second"]);
+    }
+
+    #[test]
+    fn trimmed_lines_trims_and_skips_blanks() {
+        let input = Input::from(vec!["  one", "", "two\t", " "]);
+        assert_eq!(input.trimmed_lines().unwrap(), vec!["one", "two"]);
+    }
+
+    // Input::parse_lines() tests
+
+    #[test]
+    fn parse_lines_skips_trailing_blank_line() {
+        let input = Input::from(vec!["1", " 22 ", "333", ""]);
+        let values: Vec<u32> = input.parse_lines().unwrap();
+        assert_eq!(values, vec![1, 22, 333]);
+    }
+
+    #[test]
+    fn parse_lines_reports_malformed_line_number() {
+        let input = Input::from(vec!["1", "", "2", "three", "4"]);
+        let err = input.parse_lines::<u32>().unwrap_err();
+
+        let ParseLinesError::Parse {
+            line_number,
+            line,
+            message,
+        } = err
+        else {
+            panic!("expected a parse error, got {err:?}");
+        };
+        assert_eq!(line_number, 4);
+        assert_eq!(line, "three");
+        assert_eq!(message, "invalid digit found in string");
+    }
+
+    #[test]
+    fn parse_lines_parses_negative_numbers() {
+        let input = Input::from(vec!["-7", "0", "9000000000", "-9000000000"]);
+        let values: Vec<i64> = input.parse_lines().unwrap();
+        assert_eq!(values, vec![-7, 0, 9_000_000_000, -9_000_000_000]);
+    }
+
+    #[test]
+    fn parse_lines_strict_rejects_blank_lines() {
+        let input = Input::from(vec!["1", "  ", "2"]);
+        let err = input.parse_lines_strict::<u32>().unwrap_err();
+
+        assert!(matches!(
+            err,
+            ParseLinesError::Parse { line_number: 2, ref message, .. } if message == "blank line"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "failed to parse line 2 (\"  \"): blank line"
+        );
+    }
+
+    #[test]
+    fn parse_lines_streams_file_input() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "10").unwrap();
+        writeln!(temp_file, "20").unwrap();
+        writeln!(temp_file).unwrap();
+
+        let file = std::fs::File::open(temp_file.path()).unwrap();
+        let input = Input::File(BufReader::new(file).into());
+
+        let values: Vec<u16> = input.parse_lines().unwrap();
+        assert_eq!(values, vec![10, 20]);
+    }
+
     // Input::chars() tests
 
     #[test]
@@ -340,7 +752,7 @@ mod tests {
 
         let file = std::fs::File::open(temp_file.path()).unwrap();
         let reader = BufReader::new(file);
-        let input = Input::File(reader);
+        let input = Input::File(reader.into());
 
         let chars: Vec<char> = input.chars().map(|r| r.unwrap()).collect();
         assert_eq!(chars, vec!['a', 'b', 'c']);
@@ -399,7 +811,7 @@ mod tests {
 
         let file = std::fs::File::open(temp_file.path()).unwrap();
         let reader = BufReader::new(file);
-        let input = Input::File(reader);
+        let input = Input::File(reader.into());
 
         let cloned = input.clone();
 
@@ -0,0 +1,323 @@
+//! Non-fatal warnings about the shape of the input.
+//!
+//! Puzzle input that was mangled on its way in, like trailing spaces from an editor or
+//! Windows line endings from a copy, often still parses but gives wrong answers. The
+//! [`InputInspector`] looks at each line as it's read and collects an [`InputWarning`]
+//! for the first line showing each problem. File input is inspected while the caller
+//! reads it, so its warnings end up in the shared [`InputWarnings`] at the end of the file.
+//!
+//! Leading whitespace is only reported when asked for, as some puzzles, like ones with
+//! right-aligned columns of numbers, have it in every line.
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// Lines longer than this many characters are reported by default.
+///
+/// This is well above the longest single-line puzzle inputs, so a longer line usually
+/// means lines were joined by mistake.
+pub(crate) const DEFAULT_LONG_LINE_THRESHOLD: usize = 100_000;
+
+/// A non-fatal problem found in the input.
+///
+/// Each warning carries the 1-based number of the first line with the problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputWarning {
+    /// The line ends differently than the lines before it (`\n` versus `\r\n`).
+    MixedLineEndings {
+        /// The first line with a different line ending.
+        line: usize,
+    },
+    /// The line starts with whitespace, like spaces or tabs.
+    ///
+    /// Only reported when `AOC_WARN_LEADING` is set.
+    LeadingWhitespace {
+        /// The first line with leading whitespace.
+        line: usize,
+    },
+    /// The line ends with whitespace, like spaces or tabs.
+    TrailingWhitespace {
+        /// The first line with trailing whitespace.
+        line: usize,
+    },
+    /// The line contains characters outside of ASCII.
+    NonAscii {
+        /// The first line with non-ASCII characters.
+        line: usize,
+    },
+    /// The line is unusually long.
+    LongLine {
+        /// The first line that is too long.
+        line: usize,
+        /// The number of characters in the line.
+        len: usize,
+    },
+}
+
+impl InputWarning {
+    /// The 1-based number of the first offending line.
+    pub fn line(&self) -> usize {
+        match self {
+            InputWarning::MixedLineEndings { line }
+            | InputWarning::LeadingWhitespace { line }
+            | InputWarning::TrailingWhitespace { line }
+            | InputWarning::NonAscii { line }
+            | InputWarning::LongLine { line, .. } => *line,
+        }
+    }
+
+    /// A short name for the kind of warning, for machine-readable output.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            InputWarning::MixedLineEndings { .. } => "mixed_line_endings",
+            InputWarning::LeadingWhitespace { .. } => "leading_whitespace",
+            InputWarning::TrailingWhitespace { .. } => "trailing_whitespace",
+            InputWarning::NonAscii { .. } => "non_ascii",
+            InputWarning::LongLine { .. } => "long_line",
+        }
+    }
+}
+
+impl fmt::Display for InputWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputWarning::MixedLineEndings { line } => {
+                write!(
+                    f,
+                    "line {line} has a different line ending than earlier lines"
+                )
+            }
+            InputWarning::LeadingWhitespace { line } => {
+                write!(f, "line {line} starts with whitespace")
+            }
+            InputWarning::TrailingWhitespace { line } => {
+                write!(f, "line {line} ends with whitespace")
+            }
+            InputWarning::NonAscii { line } => {
+                write!(f, "line {line} contains non-ASCII characters")
+            }
+            InputWarning::LongLine { line, len } => {
+                write!(f, "line {line} is unusually long ({len} characters)")
+            }
+        }
+    }
+}
+
+/// The warnings found in the input, shared with the reader that finds them.
+///
+/// Warnings about input from memory are here right away. Warnings about file input are
+/// found as the file is read, and are here once it has been read to the end.
+#[derive(Debug, Clone, Default)]
+pub struct InputWarnings(Arc<Mutex<Vec<InputWarning>>>);
+
+impl InputWarnings {
+    /// The warnings found so far, in the order they were found.
+    pub fn get(&self) -> Vec<InputWarning> {
+        self.lock().clone()
+    }
+
+    pub(crate) fn set(&self, warnings: Vec<InputWarning>) {
+        *self.lock() = warnings;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<InputWarning>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// How a line was terminated.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+/// Collects warnings about lines as they are read.
+#[derive(Debug)]
+pub(crate) struct InputInspector {
+    long_line_threshold: usize,
+    warn_leading_whitespace: bool,
+    line_number: usize,
+    first_ending: Option<LineEnding>,
+    warnings: Vec<InputWarning>,
+}
+
+impl InputInspector {
+    pub(crate) fn new(long_line_threshold: usize) -> Self {
+        Self {
+            long_line_threshold,
+            warn_leading_whitespace: false,
+            line_number: 0,
+            first_ending: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Sets whether lines starting with whitespace are reported.
+    pub(crate) fn warn_leading_whitespace(mut self, warn: bool) -> Self {
+        self.warn_leading_whitespace = warn;
+        self
+    }
+
+    /// Inspects the next line.
+    ///
+    /// The line may still end with its `\n` or `\r\n`, which is needed to notice mixed
+    /// line endings. Lines without an ending, like the last line of a file or lines
+    /// from arguments, are only checked for their content.
+    pub(crate) fn inspect(&mut self, raw_line: &str) {
+        self.line_number = self.line_number.saturating_add(1);
+        let line = self.line_number;
+
+        let (content, ending) = if let Some(content) = raw_line.strip_suffix("\r\n") {
+            (content, Some(LineEnding::CrLf))
+        } else if let Some(content) = raw_line.strip_suffix('\n') {
+            (content, Some(LineEnding::Lf))
+        } else {
+            (raw_line, None)
+        };
+
+        if let Some(ending) = ending {
+            match self.first_ending {
+                None => self.first_ending = Some(ending),
+                Some(first) if first != ending => {
+                    self.warn(InputWarning::MixedLineEndings { line });
+                }
+                Some(_) => {}
+            }
+        }
+
+        if self.warn_leading_whitespace && content.starts_with(char::is_whitespace) {
+            self.warn(InputWarning::LeadingWhitespace { line });
+        }
+
+        if content.ends_with(char::is_whitespace) {
+            self.warn(InputWarning::TrailingWhitespace { line });
+        }
+
+        if !content.is_ascii() {
+            self.warn(InputWarning::NonAscii { line });
+        }
+
+        let len = content.chars().count();
+        if len > self.long_line_threshold {
+            self.warn(InputWarning::LongLine { line, len });
+        }
+    }
+
+    /// Forgets everything inspected so far, to start over with another input.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::new(self.long_line_threshold)
+            .warn_leading_whitespace(self.warn_leading_whitespace);
+    }
+
+    /// Records `warning` unless a warning of the same kind was already recorded.
+    fn warn(&mut self, warning: InputWarning) {
+        if !self
+            .warnings
+            .iter()
+            .any(|seen| seen.kind() == warning.kind())
+        {
+            self.warnings.push(warning);
+        }
+    }
+
+    /// Returns the collected warnings, in the order they were found.
+    pub(crate) fn finish(self) -> Vec<InputWarning> {
+        self.warnings
+    }
+}
+
+impl Default for InputInspector {
+    fn default() -> Self {
+        Self::new(DEFAULT_LONG_LINE_THRESHOLD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inspect_all(threshold: usize, lines: &[&str]) -> Vec<InputWarning> {
+        inspect_with(InputInspector::new(threshold), lines)
+    }
+
+    fn inspect_with(mut inspector: InputInspector, lines: &[&str]) -> Vec<InputWarning> {
+        for line in lines {
+            inspector.inspect(line);
+        }
+        inspector.finish()
+    }
+
+    #[test]
+    fn clean_input_has_no_warnings() {
+        let warnings = inspect_all(10, &["abc\n", "", "12 34\n", "end"]);
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn whitespace_warnings_report_first_offending_line() {
+        let warnings = inspect_with(
+            InputInspector::new(100).warn_leading_whitespace(true),
+            &[
+                "fine",
+                "trailing ",
+                "\tindented",
+                "also trailing\t",
+                " also indented",
+            ],
+        );
+
+        assert_eq!(
+            warnings,
+            vec![
+                InputWarning::TrailingWhitespace { line: 2 },
+                InputWarning::LeadingWhitespace { line: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn leading_whitespace_is_not_reported_by_default() {
+        let warnings = inspect_all(100, &["  101  301  501\n", "  102  302  502\n"]);
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn mixed_line_endings_are_detected() {
+        let warnings = inspect_all(100, &["a\r\n", "b\r\n", "c\n", "d\r\n"]);
+        assert_eq!(warnings, vec![InputWarning::MixedLineEndings { line: 3 }]);
+    }
+
+    #[test]
+    fn consistent_crlf_line_endings_are_fine() {
+        let warnings = inspect_all(100, &["a\r\n", "b\r\n", "c"]);
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn non_ascii_is_detected() {
+        let warnings = inspect_all(100, &["plain", "caf\u{e9}", "\u{2212}5"]);
+        assert_eq!(warnings, vec![InputWarning::NonAscii { line: 2 }]);
+    }
+
+    #[test]
+    fn long_line_threshold_boundary() {
+        assert_eq!(inspect_all(5, &["12345\n"]), vec![]);
+        assert_eq!(
+            inspect_all(5, &["12345\n", "123456\n"]),
+            vec![InputWarning::LongLine { line: 2, len: 6 }]
+        );
+    }
+
+    #[test]
+    fn display_names_the_line() {
+        let warning = InputWarning::LongLine { line: 3, len: 7 };
+        assert_eq!(
+            warning.to_string(),
+            "line 3 is unusually long (7 characters)"
+        );
+        assert_eq!(warning.line(), 3);
+    }
+}
@@ -0,0 +1,487 @@
+//! Tests of the whole `read_input` flow against a scripted environment.
+
+#![expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+
+use input_reader::{ArgError, Clipboard, Environment, Error, Input, Outcome, read_input_with};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+use tempfile::TempDir;
+
+/// An environment running `day07` in a temporary directory.
+///
+/// The temporary directory is both the current directory and the default input
+/// directory. Environment variables, stdin, the clipboard and the answers to prompts are
+/// scripted, output is captured. The clipboard is a [`MockClipboard`].
+struct FakeEnvironment {
+    root: TempDir,
+    args: Vec<String>,
+    vars: HashMap<String, String>,
+    stdin: RefCell<VecDeque<String>>,
+    stdin_is_terminal: bool,
+    clipboard: Option<String>,
+    answers: RefCell<VecDeque<bool>>,
+    prompts: RefCell<Vec<String>>,
+    stdout: Output,
+    stderr: Output,
+}
+
+impl FakeEnvironment {
+    fn new(args: &[&str]) -> Self {
+        Self {
+            root: TempDir::new().unwrap(),
+            args: args.iter().map(ToString::to_string).collect(),
+            vars: HashMap::new(),
+            stdin: RefCell::default(),
+            stdin_is_terminal: false,
+            clipboard: None,
+            answers: RefCell::default(),
+            prompts: RefCell::default(),
+            stdout: Output::default(),
+            stderr: Output::default(),
+        }
+    }
+
+    /// Sets the environment variable `key` to `value`.
+    fn with_var(mut self, key: &str, value: &str) -> Self {
+        self.vars.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Scripts stdin to contain `lines`, each ending with a newline.
+    fn with_stdin(self, lines: &[&str]) -> Self {
+        self.stdin
+            .replace(lines.iter().map(|line| format!("{line}\n")).collect());
+        self
+    }
+
+    /// Makes stdin look like a terminal.
+    fn on_terminal(mut self) -> Self {
+        self.stdin_is_terminal = true;
+        self
+    }
+
+    /// Puts `text` on the clipboard.
+    fn with_clipboard(mut self, text: &str) -> Self {
+        self.clipboard = Some(text.to_string());
+        self
+    }
+
+    /// Scripts the answer to the next prompt.
+    fn answering(self, answer: bool) -> Self {
+        self.answers.borrow_mut().push_back(answer);
+        self
+    }
+
+    /// The path of the input file in the temporary directory.
+    fn input_file(&self) -> PathBuf {
+        let file_name = if cfg!(debug_assertions) {
+            "day07.txt"
+        } else {
+            "input.txt"
+        };
+        self.root.path().join(file_name)
+    }
+
+    fn prompts(&self) -> Vec<String> {
+        self.prompts.borrow().clone()
+    }
+
+    fn stdout(&self) -> String {
+        String::from_utf8(self.stdout.lock().unwrap().clone()).unwrap()
+    }
+
+    fn stderr(&self) -> String {
+        String::from_utf8(self.stderr.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Environment for FakeEnvironment {
+    fn current_exe(&self) -> io::Result<PathBuf> {
+        Ok(self.root.path().join("day07"))
+    }
+
+    fn args(&self) -> Vec<String> {
+        self.args.clone()
+    }
+
+    fn var(&self, key: &str) -> Option<String> {
+        self.vars.get(key).cloned()
+    }
+
+    fn current_dir(&self) -> io::Result<PathBuf> {
+        Ok(self.root.path().to_path_buf())
+    }
+
+    fn default_input_dir(&self) -> Option<PathBuf> {
+        Some(self.root.path().to_path_buf())
+    }
+
+    fn read_stdin_line(&self, buf: &mut String) -> io::Result<usize> {
+        let line = self.stdin.borrow_mut().pop_front().unwrap_or_default();
+        buf.push_str(&line);
+        Ok(line.len())
+    }
+
+    fn stdin_is_terminal(&self) -> bool {
+        self.stdin_is_terminal
+    }
+
+    fn confirm(&self, prompt: &str) -> bool {
+        self.prompts.borrow_mut().push(prompt.to_string());
+        self.answers.borrow_mut().pop_front().unwrap_or(false)
+    }
+
+    fn clipboard(&self) -> io::Result<Box<dyn Clipboard + '_>> {
+        Ok(Box::new(MockClipboard(self.clipboard.clone())))
+    }
+
+    fn stdout(&self) -> Box<dyn io::Write + '_> {
+        Box::new(Capture(Arc::clone(&self.stdout)))
+    }
+
+    fn stderr(&self) -> Box<dyn io::Write + Send> {
+        Box::new(Capture(Arc::clone(&self.stderr)))
+    }
+}
+
+/// A clipboard holding the given text, or no text at all.
+struct MockClipboard(Option<String>);
+
+impl Clipboard for MockClipboard {
+    fn get_text(&mut self) -> io::Result<String> {
+        self.0
+            .clone()
+            .ok_or_else(|| io::Error::other("clipboard is empty"))
+    }
+}
+
+/// Output captured from a stream, shared with the writers of the stream.
+type Output = Arc<Mutex<Vec<u8>>>;
+
+/// Appends everything written to the shared buffer.
+struct Capture(Output);
+
+impl io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reads input from `env`, returning its lines.
+fn read_lines(env: &FakeEnvironment) -> Vec<String> {
+    match read_input_with(env).unwrap() {
+        Outcome::Continue(input) => lines(input),
+        Outcome::Exit | Outcome::DryRun(_) => panic!("expected input"),
+    }
+}
+
+fn lines(input: Input) -> Vec<String> {
+    input.lines().map(Result::unwrap).collect()
+}
+
+fn read_file(path: &Path) -> String {
+    fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn auto_reads_input_file() {
+    let env = FakeEnvironment::new(&["ignored", "data"]);
+    fs::write(env.input_file(), "from file\n").unwrap();
+
+    assert_eq!(read_lines(&env), ["from file"]);
+    assert_eq!(env.stdout(), "");
+}
+
+#[test]
+fn auto_skips_empty_input_file() {
+    let env = FakeEnvironment::new(&["line1"]);
+    fs::write(env.input_file(), "").unwrap();
+
+    assert_eq!(read_lines(&env), ["line1"]);
+}
+
+#[test]
+fn whitespace_only_input_file_is_no_input() {
+    let env = FakeEnvironment::new(&["-i", "file"]);
+    fs::write(env.input_file(), "\n  \n\t\r\n\n").unwrap();
+
+    assert!(matches!(read_input_with(&env).unwrap(), Outcome::Exit));
+    assert_eq!(env.stdout(), "No input data found. Exiting.\n");
+}
+
+#[test]
+fn input_file_starting_with_blank_line_is_read() {
+    let env = FakeEnvironment::new(&["-i", "file"]);
+    fs::write(env.input_file(), "\ncontent\n").unwrap();
+
+    assert_eq!(read_lines(&env), ["", "content"]);
+}
+
+#[test]
+fn file_warnings_are_reported_at_end_of_file() {
+    let env = FakeEnvironment::new(&["-i", "file"]);
+    fs::write(env.input_file(), "a\r\nb\n").unwrap();
+
+    let Outcome::Continue(input) = read_input_with(&env).unwrap() else {
+        panic!("expected input");
+    };
+    assert_eq!(env.stderr(), "");
+
+    assert_eq!(lines(input), ["a", "b"]);
+    assert_eq!(
+        env.stderr(),
+        "Warning: Input line 2 has a different line ending than earlier lines.\n"
+    );
+}
+
+#[test]
+fn allow_empty_reads_empty_input_file() {
+    let env = FakeEnvironment::new(&["--allow-empty", "line1"]);
+    fs::write(env.input_file(), " \n").unwrap();
+
+    assert_eq!(read_lines(&env), [" "]);
+}
+
+#[test]
+fn auto_falls_back_to_args() {
+    let env = FakeEnvironment::new(&["line1", "line2"]);
+
+    assert_eq!(read_lines(&env), ["line1", "line2"]);
+    assert!(!env.input_file().exists());
+}
+
+#[test]
+fn auto_falls_back_to_stdin() {
+    let env = FakeEnvironment::new(&[])
+        .with_stdin(&["", "line1", "line2", "", ""])
+        .on_terminal();
+
+    assert_eq!(read_lines(&env), ["line1", "line2"]);
+    assert_eq!(
+        env.stdout(),
+        "Please provide the input, ending with two blank lines:\n"
+    );
+}
+
+#[test]
+fn auto_reads_piped_stdin_without_prompting() {
+    let env = FakeEnvironment::new(&[]).with_stdin(&["line1", "line2"]);
+
+    assert_eq!(read_lines(&env), ["line1", "line2"]);
+    assert_eq!(env.stdout(), "");
+}
+
+#[test]
+fn piped_stdin_is_read_past_blank_lines() {
+    let env = FakeEnvironment::new(&["-i", "stdin"]).with_stdin(&["", "a", "", "", "b", "", ""]);
+
+    assert_eq!(read_lines(&env), ["a", "", "", "b"]);
+}
+
+#[test]
+fn auto_exits_without_any_input() {
+    let env = FakeEnvironment::new(&[]);
+
+    assert!(matches!(read_input_with(&env).unwrap(), Outcome::Exit));
+    assert!(env.stdout().ends_with("No input data found. Exiting.\n"));
+}
+
+#[test]
+fn save_creates_new_input_file() {
+    let env = FakeEnvironment::new(&["--save", "line1", "line2"]);
+
+    assert_eq!(read_lines(&env), ["line1", "line2"]);
+    assert_eq!(read_file(&env.input_file()), "line1\nline2\n");
+    assert_eq!(env.prompts(), Vec::<String>::new());
+}
+
+#[test]
+fn save_asks_before_overwriting_and_can_be_declined() {
+    let env = FakeEnvironment::new(&["-si", "args", "new"]).answering(false);
+    fs::write(env.input_file(), "old\n").unwrap();
+
+    assert_eq!(read_lines(&env), ["new"]);
+    assert_eq!(read_file(&env.input_file()), "old\n");
+    assert_eq!(
+        env.prompts(),
+        ["Input file already exists. Overwrite? (y/N): "]
+    );
+    assert!(env.stdout().ends_with("Save aborted.\n"));
+}
+
+#[test]
+fn save_overwrites_when_confirmed() {
+    let env = FakeEnvironment::new(&["-si", "args", "new"]).answering(true);
+    fs::write(env.input_file(), "old\n").unwrap();
+
+    assert_eq!(read_lines(&env), ["new"]);
+    assert_eq!(read_file(&env.input_file()), "new\n");
+    assert_eq!(env.prompts().len(), 1);
+}
+
+#[test]
+fn save_forced_overwrites_without_asking() {
+    let env = FakeEnvironment::new(&["-sfi", "args", "new"]);
+    fs::write(env.input_file(), "old\n").unwrap();
+
+    assert_eq!(read_lines(&env), ["new"]);
+    assert_eq!(read_file(&env.input_file()), "new\n");
+    assert_eq!(env.prompts(), Vec::<String>::new());
+}
+
+#[test]
+fn save_is_refused_for_possibly_truncated_terminal_input() {
+    let long_line = "x".repeat(4000);
+    let env = FakeEnvironment::new(&["-si", "stdin"])
+        .with_stdin(&[&long_line, "", ""])
+        .on_terminal();
+
+    assert_eq!(read_lines(&env), [long_line.as_str()]);
+    assert!(!env.input_file().exists());
+    assert_eq!(
+        env.stderr(),
+        "\
+Warning: Input line may have been truncated (TTY buffer limit).
+Warning: Save refused due to potential data truncation.
+"
+    );
+}
+
+#[test]
+fn long_piped_input_is_saved() {
+    let long_line = "x".repeat(4000);
+    let env = FakeEnvironment::new(&["-si", "stdin"]).with_stdin(&[&long_line]);
+
+    assert_eq!(read_lines(&env), [long_line.as_str()]);
+    assert_eq!(read_file(&env.input_file()), format!("{long_line}\n"));
+}
+
+#[test]
+fn clipboard_input_is_read_and_saved() {
+    let env =
+        FakeEnvironment::new(&["--clipboard", "--save"]).with_clipboard("first\r\nsecond\r\n");
+
+    assert_eq!(read_lines(&env), ["first", "second"]);
+    assert_eq!(read_file(&env.input_file()), "first\nsecond\n");
+    assert_eq!(env.stderr(), "");
+}
+
+#[test]
+fn save_is_refused_for_possibly_truncated_clipboard_input() {
+    let long_line = "x".repeat(5000);
+    let env = FakeEnvironment::new(&["--clipboard", "--save"])
+        .with_clipboard(&format!("first\n{long_line}\n"));
+
+    assert_eq!(read_lines(&env), ["first", long_line.as_str()]);
+    assert!(!env.input_file().exists());
+    assert_eq!(
+        env.stderr(),
+        "\
+Warning: Input line may have been truncated (TTY buffer limit).
+Warning: Save refused due to potential data truncation.
+"
+    );
+}
+
+#[test]
+fn clipboard_failure_warns_and_exits() {
+    let env = FakeEnvironment::new(&["--clipboard"]);
+
+    assert!(matches!(read_input_with(&env).unwrap(), Outcome::Exit));
+    assert_eq!(
+        env.stderr(),
+        "Warning: Could not read the clipboard: clipboard is empty.\n"
+    );
+}
+
+#[test]
+fn save_honors_input_template() {
+    let env = FakeEnvironment::new(&["--save", "line1"])
+        .with_var("AOC_INPUT_TEMPLATE", "inputs/{day:02}/input.txt");
+
+    assert_eq!(read_lines(&env), ["line1"]);
+    assert_eq!(
+        read_file(&env.root.path().join("inputs/07/input.txt")),
+        "line1\n"
+    );
+    assert!(!env.input_file().exists());
+}
+
+#[test]
+fn templated_input_file_is_read_first() {
+    let env = FakeEnvironment::new(&["-i", "file"])
+        .with_var("AOC_INPUT_TEMPLATE", "inputs/day-{day}.txt");
+    fs::create_dir(env.root.path().join("inputs")).unwrap();
+    fs::write(env.root.path().join("inputs/day-7.txt"), "templated\n").unwrap();
+    fs::write(env.input_file(), "flat\n").unwrap();
+
+    assert_eq!(read_lines(&env), ["templated"]);
+}
+
+#[test]
+fn template_year_without_year_in_identifier_is_an_error() {
+    let env = FakeEnvironment::new(&["line1"]).with_var("AOC_INPUT_TEMPLATE", "{year}/{day}.txt");
+
+    let result = read_input_with(&env);
+
+    assert!(matches!(
+        result,
+        Err(Error::InvalidTemplate { template, .. }) if template == "{year}/{day}.txt"
+    ));
+}
+
+#[test]
+fn dry_run_reads_input_file() {
+    let env = FakeEnvironment::new(&["--dry-run"]);
+    fs::write(env.input_file(), "a\nb\nc\n").unwrap();
+
+    let Outcome::DryRun(input) = read_input_with(&env).unwrap() else {
+        panic!("expected a dry run");
+    };
+    assert_eq!(lines(input), ["a", "b", "c"]);
+}
+
+#[test]
+fn unknown_flag_is_an_argument_error() {
+    let env = FakeEnvironment::new(&["--sav"]);
+
+    let result = read_input_with(&env);
+
+    assert!(matches!(
+        result,
+        Err(Error::Args(ArgError::UnknownFlag(flag))) if flag == "--sav"
+    ));
+    assert_eq!(env.stdout(), "");
+}
+
+#[test]
+fn verbose_reports_identifier() {
+    let env = FakeEnvironment::new(&["-v", "line1"]);
+
+    assert_eq!(read_lines(&env), ["line1"]);
+    assert_eq!(
+        env.stderr(),
+        "Using identifier: day07 (from executable day07)\n"
+    );
+}
+
+#[test]
+fn help_is_written_to_stdout() {
+    let env = FakeEnvironment::new(&["--help"]);
+
+    assert!(matches!(read_input_with(&env).unwrap(), Outcome::Exit));
+    assert!(
+        env.stdout()
+            .starts_with("USAGE: day07 [OPTIONS] [DATA...]\n")
+    );
+}
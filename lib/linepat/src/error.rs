@@ -0,0 +1,76 @@
+//! Errors from matching a line against a pattern.
+
+use std::{error, fmt};
+
+/// Why a line didn't match a [`Pattern`](crate::Pattern).
+///
+/// Word positions are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchError {
+    /// A word differs from the literal the pattern expects there.
+    LiteralMismatch {
+        /// The position of the word.
+        position: usize,
+        /// The literal the pattern expects.
+        expected: String,
+        /// The word that was found instead.
+        found: String,
+    },
+    /// The line ended before the pattern did.
+    TooFewWords {
+        /// The number of words in the pattern.
+        expected: usize,
+        /// The number of words in the line.
+        found: usize,
+    },
+    /// The line has words left after the pattern ended.
+    TooManyWords {
+        /// The number of words in the pattern.
+        expected: usize,
+        /// The number of words in the line.
+        found: usize,
+    },
+    /// A captured word couldn't be parsed.
+    ParseFailed {
+        /// The position of the word.
+        position: usize,
+        /// The name of the capture.
+        field: String,
+        /// The word that couldn't be parsed.
+        token: String,
+        /// The message of the underlying parse error.
+        message: String,
+    },
+}
+
+impl fmt::Display for MatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchError::LiteralMismatch {
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {expected:?} at word {position}, found {found:?}"
+            ),
+            MatchError::TooFewWords { expected, found } => {
+                write!(f, "expected {expected} words, found only {found}")
+            }
+            MatchError::TooManyWords { expected, found } => {
+                write!(f, "expected {expected} words, found {found}")
+            }
+            MatchError::ParseFailed {
+                position,
+                field,
+                token,
+                message,
+            } => write!(
+                f,
+                "failed to parse {field} from {token:?} at word {position}: {message}"
+            ),
+        }
+    }
+}
+
+impl error::Error for MatchError {}
@@ -0,0 +1,13 @@
+//! Word pattern matching for puzzle input lines.
+//!
+//! Many puzzle inputs are sentences with a fixed wording, like
+//! `London to Dublin = 464`. A [`Pattern`] describes such a line word by word, as
+//! literal words that must be there and captures that are kept, optionally parsed
+//! into a type. Matching a line gives the [`Captures`], or a [`MatchError`] saying
+//! which word didn't fit.
+
+mod error;
+mod pattern;
+
+pub use crate::error::MatchError;
+pub use crate::pattern::{Captures, Pattern};
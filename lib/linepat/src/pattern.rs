@@ -0,0 +1,291 @@
+//! Patterns and the words they capture.
+
+use crate::error::MatchError;
+use std::{any::Any, collections::HashMap, fmt, str::FromStr};
+
+/// Turns a captured word into its value, or an error message.
+type ParseFn = fn(&str) -> Result<Box<dyn Any>, String>;
+
+/// One word of a pattern.
+#[derive(Debug, Clone)]
+enum Element {
+    /// A word that must appear as is.
+    Literal(String),
+    /// A word that is kept under `name`.
+    Capture { name: String, parse: ParseFn },
+}
+
+/// A line described word by word.
+///
+/// Words are separated by whitespace, and a line matches only if it has exactly as
+/// many words as the pattern.
+///
+/// ```
+/// use linepat::Pattern;
+///
+/// let pattern = Pattern::new()
+///     .capture("from")
+///     .lit("to")
+///     .capture("to")
+///     .lit("=")
+///     .capture_parse::<u32>("distance");
+///
+/// let captures = pattern.matches("London to Dublin = 464").unwrap();
+/// assert_eq!(captures.str("from"), Some("London"));
+/// assert_eq!(captures.get::<u32>("distance"), Some(&464));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Pattern {
+    elements: Vec<Element>,
+}
+
+impl Pattern {
+    /// Creates an empty pattern, which only matches blank lines.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects the next word to be `word`.
+    #[must_use]
+    pub fn lit(mut self, word: &str) -> Self {
+        self.elements.push(Element::Literal(word.to_string()));
+        self
+    }
+
+    /// Captures the next word as text under `name`.
+    ///
+    /// Get it with [`Captures::str`].
+    #[must_use]
+    pub fn capture(self, name: &str) -> Self {
+        self.capture_parse::<String>(name)
+    }
+
+    /// Captures the next word parsed as a `T` under `name`.
+    ///
+    /// Get it with [`Captures::get`]. A word that doesn't parse fails the match.
+    #[must_use]
+    pub fn capture_parse<T>(mut self, name: &str) -> Self
+    where
+        T: FromStr + 'static,
+        T::Err: fmt::Display,
+    {
+        self.elements.push(Element::Capture {
+            name: name.to_string(),
+            parse: parse_any::<T>,
+        });
+        self
+    }
+
+    /// Matches `line` against the pattern, returning what was captured.
+    ///
+    /// Words are checked in order, so the error is about the first word that doesn't
+    /// fit. A capture given the same name twice keeps the last word.
+    pub fn matches(&self, line: &str) -> Result<Captures, MatchError> {
+        let mut words = line.split_whitespace();
+        let mut values = HashMap::new();
+
+        for (element, position) in self.elements.iter().zip(1_usize..) {
+            let word = words.next().ok_or(MatchError::TooFewWords {
+                expected: self.elements.len(),
+                found: position.saturating_sub(1),
+            })?;
+
+            match element {
+                Element::Literal(expected) => {
+                    if word != expected {
+                        return Err(MatchError::LiteralMismatch {
+                            position,
+                            expected: expected.clone(),
+                            found: word.to_string(),
+                        });
+                    }
+                }
+                Element::Capture { name, parse } => {
+                    let value = parse(word).map_err(|message| MatchError::ParseFailed {
+                        position,
+                        field: name.clone(),
+                        token: word.to_string(),
+                        message,
+                    })?;
+                    values.insert(name.clone(), value);
+                }
+            }
+        }
+
+        let extra = words.count();
+        if extra > 0 {
+            return Err(MatchError::TooManyWords {
+                expected: self.elements.len(),
+                found: self.elements.len().saturating_add(extra),
+            });
+        }
+
+        Ok(Captures { values })
+    }
+}
+
+/// Parses `word` as a `T`, boxing it up for [`Captures`].
+fn parse_any<T>(word: &str) -> Result<Box<dyn Any>, String>
+where
+    T: FromStr + 'static,
+    T::Err: fmt::Display,
+{
+    match word.parse::<T>() {
+        Ok(value) => Ok(Box::new(value)),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// The words captured by a successful match, by name.
+pub struct Captures {
+    values: HashMap<String, Box<dyn Any>>,
+}
+
+impl Captures {
+    /// Gets the value captured as `name`.
+    ///
+    /// Returns `None` if there is no such capture, or if it wasn't parsed as a `T`.
+    pub fn get<T: Any>(&self, name: &str) -> Option<&T> {
+        self.values.get(name)?.downcast_ref()
+    }
+
+    /// Gets the text captured as `name` with [`Pattern::capture`].
+    pub fn str(&self, name: &str) -> Option<&str> {
+        self.get::<String>(name).map(String::as_str)
+    }
+}
+
+impl fmt::Debug for Captures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names: Vec<_> = self.values.keys().collect();
+        names.sort();
+        f.debug_struct("Captures").field("names", &names).finish()
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+mod tests {
+    use super::*;
+
+    fn distance() -> Pattern {
+        Pattern::new()
+            .capture("from")
+            .lit("to")
+            .capture("to")
+            .lit("=")
+            .capture_parse::<u32>("distance")
+    }
+
+    fn happiness() -> Pattern {
+        Pattern::new()
+            .capture("guest")
+            .lit("would")
+            .capture("change")
+            .capture_parse::<i32>("units")
+            .lit("happiness")
+            .lit("units")
+            .lit("by")
+            .lit("sitting")
+            .lit("next")
+            .lit("to")
+            .capture("neighbor")
+    }
+
+    #[test]
+    fn matching_line_captures_words() {
+        let captures = distance().matches("London to Dublin = 464").unwrap();
+
+        assert_eq!(captures.str("from"), Some("London"));
+        assert_eq!(captures.str("to"), Some("Dublin"));
+        assert_eq!(captures.get::<u32>("distance"), Some(&464));
+    }
+
+    #[test]
+    fn matching_ignores_extra_whitespace() {
+        let captures = happiness()
+            .matches("  Alice would lose  79 happiness units by sitting next to Carol.\t")
+            .unwrap();
+
+        assert_eq!(captures.str("guest"), Some("Alice"));
+        assert_eq!(captures.str("change"), Some("lose"));
+        assert_eq!(captures.get::<i32>("units"), Some(&79));
+        assert_eq!(captures.str("neighbor"), Some("Carol."));
+    }
+
+    #[test]
+    fn get_needs_the_captured_type() {
+        let captures = distance().matches("London to Dublin = 464").unwrap();
+
+        assert_eq!(captures.get::<i64>("distance"), None);
+        assert_eq!(captures.str("distance"), None);
+        assert_eq!(captures.get::<u32>("missing"), None);
+    }
+
+    #[test]
+    fn literal_mismatch_names_position() {
+        let err = distance().matches("London from Dublin = 464").unwrap_err();
+
+        assert_eq!(
+            err,
+            MatchError::LiteralMismatch {
+                position: 2,
+                expected: "to".to_string(),
+                found: "from".to_string(),
+            }
+        );
+        assert_eq!(err.to_string(), "expected \"to\" at word 2, found \"from\"");
+    }
+
+    #[test]
+    fn too_few_words() {
+        let err = distance().matches("London to Dublin").unwrap_err();
+        assert_eq!(
+            err,
+            MatchError::TooFewWords {
+                expected: 5,
+                found: 3
+            }
+        );
+    }
+
+    #[test]
+    fn too_many_words() {
+        let err = distance().matches("London to Dublin = 464 km").unwrap_err();
+        assert_eq!(
+            err,
+            MatchError::TooManyWords {
+                expected: 5,
+                found: 6
+            }
+        );
+    }
+
+    #[test]
+    fn parse_failure_names_field_and_token() {
+        let err = distance().matches("London to Dublin = far").unwrap_err();
+
+        let MatchError::ParseFailed {
+            position,
+            field,
+            token,
+            ..
+        } = &err
+        else {
+            panic!("expected a parse failure, got {err:?}");
+        };
+        assert_eq!(*position, 5);
+        assert_eq!(field, "distance");
+        assert_eq!(token, "far");
+        assert!(
+            err.to_string()
+                .starts_with("failed to parse distance from \"far\" at word 5: ")
+        );
+    }
+
+    #[test]
+    fn empty_pattern_matches_blank_lines() {
+        assert!(Pattern::new().matches("   ").is_ok());
+        assert!(Pattern::new().matches("word").is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! Unweighted graph search.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// A graph of states that can be searched for a goal.
+///
+/// Implementors describe the graph, the provided methods search it.
+pub trait GraphSearch<State: Hash + Eq + Clone> {
+    /// The states the search starts from.
+    fn start_states(&self) -> Vec<State>;
+
+    /// The states reachable from `state` in one step.
+    fn successors(&self, state: &State) -> Vec<State>;
+
+    /// Whether `state` is a goal.
+    fn is_goal(&self, state: &State) -> bool;
+
+    /// Searches breadth-first for the goal closest to any start state.
+    ///
+    /// Returns the states along the path, from a start state to the goal, or `None`
+    /// if no goal is reachable. Each state is visited at most once, so the search
+    /// ends on finite graphs even if they have cycles.
+    fn bfs(&self) -> Option<Vec<State>> {
+        let mut parents = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        for state in self.start_states() {
+            if !parents.contains_key(&state) {
+                parents.insert(state.clone(), None);
+                queue.push_back(state);
+            }
+        }
+
+        while let Some(state) = queue.pop_front() {
+            if self.is_goal(&state) {
+                return Some(path_to(&parents, state));
+            }
+
+            for successor in self.successors(&state) {
+                if !parents.contains_key(&successor) {
+                    parents.insert(successor.clone(), Some(state.clone()));
+                    queue.push_back(successor);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Follows the parents back from `goal` to a start state, returning the path in order.
+pub(crate) fn path_to<State: Hash + Eq + Clone>(
+    parents: &HashMap<State, Option<State>>,
+    goal: State,
+) -> Vec<State> {
+    let mut path = vec![goal];
+
+    while let Some(Some(parent)) = path.last().and_then(|state| parents.get(state)) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+mod tests {
+    use super::*;
+
+    /// A maze of open cells (`.`) and walls (`#`), with starts (`S`) and goals (`G`).
+    struct Maze {
+        cells: Vec<Vec<char>>,
+    }
+
+    type Position = (usize, usize);
+
+    impl Maze {
+        fn new(rows: &[&str]) -> Self {
+            Self {
+                cells: rows.iter().map(|row| row.chars().collect()).collect(),
+            }
+        }
+
+        fn cell(&self, (x, y): Position) -> Option<char> {
+            self.cells.get(y).and_then(|row| row.get(x)).copied()
+        }
+
+        fn positions_of(&self, wanted: char) -> Vec<Position> {
+            self.cells
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(move |&(_, &cell)| cell == wanted)
+                        .map(move |(x, _)| (x, y))
+                })
+                .collect()
+        }
+    }
+
+    impl GraphSearch<Position> for Maze {
+        fn start_states(&self) -> Vec<Position> {
+            self.positions_of('S')
+        }
+
+        fn successors(&self, &(x, y): &Position) -> Vec<Position> {
+            [
+                x.checked_sub(1).map(|x| (x, y)),
+                x.checked_add(1).map(|x| (x, y)),
+                y.checked_sub(1).map(|y| (x, y)),
+                y.checked_add(1).map(|y| (x, y)),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|&position| self.cell(position).is_some_and(|cell| cell != '#'))
+            .collect()
+        }
+
+        fn is_goal(&self, &position: &Position) -> bool {
+            self.cell(position) == Some('G')
+        }
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path_through_maze() {
+        let maze = Maze::new(&["S.#...", ".##.#.", "....#G", "##...."]);
+
+        let path = maze.bfs().unwrap();
+
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(5, 2)));
+        assert_eq!(path.len(), 10);
+    }
+
+    #[test]
+    fn bfs_path_only_takes_single_open_steps() {
+        let maze = Maze::new(&["S..#", "#.#.", "#..G"]);
+
+        let path = maze.bfs().unwrap();
+
+        assert!(
+            path.windows(2)
+                .all(|pair| matches!(pair, [from, to] if maze.successors(from).contains(to)))
+        );
+    }
+
+    #[test]
+    fn bfs_returns_none_when_goal_is_walled_off() {
+        let maze = Maze::new(&["S.#.", "..#G"]);
+        assert_eq!(maze.bfs(), None);
+    }
+
+    #[test]
+    fn bfs_returns_none_without_goal() {
+        let maze = Maze::new(&["S..", "..."]);
+        assert_eq!(maze.bfs(), None);
+    }
+
+    #[test]
+    fn bfs_returns_start_when_it_is_a_goal() {
+        struct Single;
+
+        impl GraphSearch<u8> for Single {
+            fn start_states(&self) -> Vec<u8> {
+                vec![7]
+            }
+
+            fn successors(&self, _: &u8) -> Vec<u8> {
+                vec![]
+            }
+
+            fn is_goal(&self, state: &u8) -> bool {
+                *state == 7
+            }
+        }
+
+        assert_eq!(Single.bfs(), Some(vec![7]));
+    }
+
+    #[test]
+    fn bfs_starts_from_the_closest_start_state() {
+        let maze = Maze::new(&["S....G..S"]);
+
+        let path = maze.bfs().unwrap();
+
+        assert_eq!(path, vec![(8, 0), (7, 0), (6, 0), (5, 0)]);
+    }
+}
@@ -0,0 +1,14 @@
+//! Search library for puzzle solutions.
+//!
+//! Puzzles describe their own world through the [`GraphSearch`] trait: where a
+//! search starts, which states follow from a state, and what counts as a goal.
+//! The trait then provides the search itself, like [`GraphSearch::bfs`].
+//!
+//! When steps have different costs, [`PrioritySearch`] adds them, and an optional
+//! estimate of the cost left, for [`PrioritySearch::astar`].
+
+mod graph;
+mod priority;
+
+pub use crate::graph::GraphSearch;
+pub use crate::priority::PrioritySearch;
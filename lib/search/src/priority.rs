@@ -0,0 +1,268 @@
+//! Weighted graph search.
+
+use crate::graph::{GraphSearch, path_to};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+/// A graph whose steps have costs, searched for the cheapest path to a goal.
+///
+/// Without a [`heuristic`](PrioritySearch::heuristic), [`astar`](PrioritySearch::astar)
+/// searches like Dijkstra's algorithm.
+pub trait PrioritySearch<State: Hash + Eq + Clone>: GraphSearch<State> {
+    /// The cost of stepping from `from` to its successor `to`.
+    ///
+    /// Costs should not be negative.
+    fn cost(&self, from: &State, to: &State) -> i64;
+
+    /// An estimate of the cost left from `state` to the closest goal.
+    ///
+    /// The estimate must never be more than the actual cost, or the path found may not
+    /// be the cheapest. Defaults to `0`, which is always safe.
+    fn heuristic(&self, _state: &State) -> i64 {
+        0
+    }
+
+    /// Searches for the cheapest path from any start state to a goal.
+    ///
+    /// Returns the states along the path, from a start state to the goal, together
+    /// with its total cost, or `None` if no goal is reachable. Costs saturate instead
+    /// of overflowing.
+    fn astar(&self) -> Option<(Vec<State>, i64)> {
+        let mut best_costs = HashMap::new();
+        let mut parents = HashMap::new();
+        let mut queue = BinaryHeap::new();
+
+        for state in self.start_states() {
+            if !best_costs.contains_key(&state) {
+                best_costs.insert(state.clone(), 0);
+                parents.insert(state.clone(), None);
+                queue.push(Entry {
+                    priority: self.heuristic(&state),
+                    cost: 0,
+                    state,
+                });
+            }
+        }
+
+        while let Some(Entry { cost, state, .. }) = queue.pop() {
+            // A cheaper way to this state was found after this entry was queued.
+            if best_costs.get(&state).is_some_and(|&best| best < cost) {
+                continue;
+            }
+
+            if self.is_goal(&state) {
+                return Some((path_to(&parents, state), cost));
+            }
+
+            for successor in self.successors(&state) {
+                let successor_cost = cost.saturating_add(self.cost(&state, &successor));
+
+                if best_costs
+                    .get(&successor)
+                    .is_none_or(|&best| successor_cost < best)
+                {
+                    best_costs.insert(successor.clone(), successor_cost);
+                    parents.insert(successor.clone(), Some(state.clone()));
+                    queue.push(Entry {
+                        priority: successor_cost.saturating_add(self.heuristic(&successor)),
+                        cost: successor_cost,
+                        state: successor,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// A queued state, ordered so the lowest priority is popped first.
+struct Entry<State> {
+    priority: i64,
+    cost: i64,
+    state: State,
+}
+
+impl<State> PartialEq for Entry<State> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<State> Eq for Entry<State> {}
+
+impl<State> PartialOrd for Entry<State> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<State> Ord for Entry<State> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+mod tests {
+    use super::*;
+
+    /// A maze where entering a cell costs its digit, with walls (`#`), a start (`S`)
+    /// and a goal (`G`). The start and goal cost 1 to enter.
+    struct Maze {
+        cells: Vec<Vec<char>>,
+        use_heuristic: bool,
+    }
+
+    type Position = (usize, usize);
+
+    impl Maze {
+        fn new(rows: &[&str], use_heuristic: bool) -> Self {
+            Self {
+                cells: rows.iter().map(|row| row.chars().collect()).collect(),
+                use_heuristic,
+            }
+        }
+
+        fn cell(&self, (x, y): Position) -> Option<char> {
+            self.cells.get(y).and_then(|row| row.get(x)).copied()
+        }
+
+        fn position_of(&self, wanted: char) -> Option<Position> {
+            self.cells
+                .iter()
+                .enumerate()
+                .find_map(|(y, row)| row.iter().position(|&cell| cell == wanted).map(|x| (x, y)))
+        }
+    }
+
+    impl GraphSearch<Position> for Maze {
+        fn start_states(&self) -> Vec<Position> {
+            self.position_of('S').into_iter().collect()
+        }
+
+        fn successors(&self, &(x, y): &Position) -> Vec<Position> {
+            [
+                x.checked_sub(1).map(|x| (x, y)),
+                x.checked_add(1).map(|x| (x, y)),
+                y.checked_sub(1).map(|y| (x, y)),
+                y.checked_add(1).map(|y| (x, y)),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|&position| self.cell(position).is_some_and(|cell| cell != '#'))
+            .collect()
+        }
+
+        fn is_goal(&self, &position: &Position) -> bool {
+            self.cell(position) == Some('G')
+        }
+    }
+
+    impl PrioritySearch<Position> for Maze {
+        fn cost(&self, _from: &Position, &to: &Position) -> i64 {
+            self.cell(to)
+                .and_then(|cell| cell.to_digit(10))
+                .map_or(1, i64::from)
+        }
+
+        fn heuristic(&self, &(x, y): &Position) -> i64 {
+            if !self.use_heuristic {
+                return 0;
+            }
+
+            let (goal_x, goal_y) = self.position_of('G').unwrap();
+            let distance = x.abs_diff(goal_x).saturating_add(y.abs_diff(goal_y));
+            i64::try_from(distance).unwrap()
+        }
+    }
+
+    const WEIGHTED: [&str; 4] = ["S9111", "19191", "11191", "9999G"];
+
+    #[test]
+    fn astar_finds_cheapest_path() {
+        let maze = Maze::new(&WEIGHTED, true);
+
+        let (path, cost) = maze.astar().unwrap();
+
+        // Down the left side, then along the ones winding around the nines.
+        assert_eq!(cost, 11);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 3)));
+    }
+
+    #[test]
+    fn astar_without_heuristic_matches_with_heuristic() {
+        let with = Maze::new(&WEIGHTED, true).astar().unwrap();
+        let without = Maze::new(&WEIGHTED, false).astar().unwrap();
+
+        assert_eq!(with.1, without.1);
+    }
+
+    #[test]
+    fn astar_path_cost_adds_up() {
+        let maze = Maze::new(&WEIGHTED, true);
+
+        let (path, cost) = maze.astar().unwrap();
+        let total: i64 = path
+            .windows(2)
+            .filter_map(|pair| match pair {
+                [from, to] => Some(maze.cost(from, to)),
+                _ => None,
+            })
+            .sum();
+
+        assert_eq!(total, cost);
+    }
+
+    #[test]
+    fn astar_with_unit_costs_matches_bfs() {
+        let maze = Maze::new(&["S..#....", ".#.#.##.", ".#...#..", "...#...G"], true);
+
+        let (path, cost) = maze.astar().unwrap();
+        let bfs_path = maze.bfs().unwrap();
+
+        assert_eq!(path.len(), bfs_path.len());
+        assert_eq!(
+            cost,
+            i64::try_from(bfs_path.len().saturating_sub(1)).unwrap()
+        );
+    }
+
+    #[test]
+    fn astar_returns_none_when_goal_is_walled_off() {
+        let maze = Maze::new(&["S1#1", "11#G"], true);
+        assert_eq!(maze.astar(), None);
+    }
+
+    #[test]
+    fn astar_returns_start_when_it_is_a_goal() {
+        struct Single;
+
+        impl GraphSearch<u8> for Single {
+            fn start_states(&self) -> Vec<u8> {
+                vec![3]
+            }
+
+            fn successors(&self, _: &u8) -> Vec<u8> {
+                vec![4]
+            }
+
+            fn is_goal(&self, state: &u8) -> bool {
+                *state == 3
+            }
+        }
+
+        impl PrioritySearch<u8> for Single {
+            fn cost(&self, _: &u8, _: &u8) -> i64 {
+                1
+            }
+        }
+
+        assert_eq!(Single.astar(), Some((vec![3], 0)));
+    }
+}
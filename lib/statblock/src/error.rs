@@ -0,0 +1,89 @@
+//! Errors from parsing a stat block.
+
+use std::{error, fmt};
+
+/// Why a stat block couldn't be parsed.
+///
+/// Line numbers are 1-based and count blank lines too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatBlockError {
+    /// A line has no `:` between the key and the value.
+    MissingSeparator {
+        /// The number of the line.
+        line_number: usize,
+        /// The line itself.
+        line: String,
+    },
+    /// A key isn't one of the caller's keys.
+    UnknownKey {
+        /// The number of the line.
+        line_number: usize,
+        /// The text of the key.
+        key: String,
+    },
+    /// A key appears more than once.
+    DuplicateKey {
+        /// The number of the line with the second occurrence.
+        line_number: usize,
+        /// The text of the key.
+        key: String,
+    },
+    /// A value isn't a number.
+    InvalidValue {
+        /// The number of the line.
+        line_number: usize,
+        /// The text of the key.
+        key: String,
+        /// The text of the value.
+        value: String,
+        /// The message of the underlying parse error.
+        message: String,
+    },
+    /// A value was rejected by the caller's validation.
+    OutOfRange {
+        /// The number of the line.
+        line_number: usize,
+        /// The text of the key.
+        key: String,
+        /// The rejected value.
+        value: i64,
+        /// Why the value was rejected.
+        message: String,
+    },
+}
+
+impl fmt::Display for StatBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatBlockError::MissingSeparator { line_number, line } => {
+                write!(f, "line {line_number} ({line:?}) has no ':'")
+            }
+            StatBlockError::UnknownKey { line_number, key } => {
+                write!(f, "unknown key {key:?} on line {line_number}")
+            }
+            StatBlockError::DuplicateKey { line_number, key } => {
+                write!(f, "duplicate key {key:?} on line {line_number}")
+            }
+            StatBlockError::InvalidValue {
+                line_number,
+                key,
+                value,
+                message,
+            } => write!(
+                f,
+                "failed to parse {key} from {value:?} on line {line_number}: {message}"
+            ),
+            StatBlockError::OutOfRange {
+                line_number,
+                key,
+                value,
+                message,
+            } => write!(
+                f,
+                "{key} of {value} on line {line_number} is out of range: {message}"
+            ),
+        }
+    }
+}
+
+impl error::Error for StatBlockError {}
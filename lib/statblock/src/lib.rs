@@ -0,0 +1,236 @@
+//! Parsing of `Key: value` stat blocks in puzzle input.
+//!
+//! Some puzzle inputs describe a character or an item as one stat per line, like
+//! `Hit Points: 104`. The keys are known up front, so the caller names them with its
+//! own type, and [`parse_stat_block`] maps each of them to its value. Anything off
+//! about the block, like an unknown or repeated key, is a [`StatBlockError`].
+
+mod error;
+
+pub use crate::error::StatBlockError;
+use std::{collections::HashMap, hash::Hash, str::FromStr};
+
+/// Parses the `Key: value` lines of a stat block into a map from key to value.
+///
+/// Keys are parsed into a `K` and values into an `i64`, both with surrounding
+/// whitespace trimmed. Blank lines are skipped, so an empty block gives an empty map,
+/// and checking that the needed keys are there is up to the caller.
+///
+/// ```
+/// use statblock::parse_stat_block;
+///
+/// let lines = ["Hit Points: 104", "Damage: 8"].map(String::from);
+/// let stats = parse_stat_block::<String>(lines.into_iter()).unwrap();
+///
+/// assert_eq!(stats.get("Hit Points"), Some(&104));
+/// assert_eq!(stats.get("Damage"), Some(&8));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error for the first line that has no `:`, an unknown or repeated key,
+/// or a value that isn't a number.
+pub fn parse_stat_block<K>(
+    lines: impl Iterator<Item = String>,
+) -> Result<HashMap<K, i64>, StatBlockError>
+where
+    K: FromStr + Eq + Hash,
+{
+    parse_stat_block_with(lines, |_, _| Ok(()))
+}
+
+/// Parses a stat block like [`parse_stat_block`], checking each value with `validate`.
+///
+/// `validate` gets the key and the value, and returns why the value is out of range
+/// if it is.
+///
+/// # Errors
+///
+/// Returns the errors of [`parse_stat_block`], and [`StatBlockError::OutOfRange`] for
+/// the first value `validate` rejects.
+pub fn parse_stat_block_with<K, F>(
+    lines: impl Iterator<Item = String>,
+    mut validate: F,
+) -> Result<HashMap<K, i64>, StatBlockError>
+where
+    K: FromStr + Eq + Hash,
+    F: FnMut(&K, i64) -> Result<(), String>,
+{
+    let mut stats = HashMap::new();
+
+    for (line, line_number) in lines.zip(1_usize..) {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let Some((key_text, value_text)) = line.split_once(':') else {
+            return Err(StatBlockError::MissingSeparator { line_number, line });
+        };
+        let (key_text, value_text) = (key_text.trim(), value_text.trim());
+
+        let Ok(key) = key_text.parse::<K>() else {
+            return Err(StatBlockError::UnknownKey {
+                line_number,
+                key: key_text.to_string(),
+            });
+        };
+
+        let value = value_text
+            .parse::<i64>()
+            .map_err(|err| StatBlockError::InvalidValue {
+                line_number,
+                key: key_text.to_string(),
+                value: value_text.to_string(),
+                message: err.to_string(),
+            })?;
+
+        validate(&key, value).map_err(|message| StatBlockError::OutOfRange {
+            line_number,
+            key: key_text.to_string(),
+            value,
+            message,
+        })?;
+
+        if stats.insert(key, value).is_some() {
+            return Err(StatBlockError::DuplicateKey {
+                line_number,
+                key: key_text.to_string(),
+            });
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Stat {
+        HitPoints,
+        Damage,
+        Armor,
+    }
+
+    impl FromStr for Stat {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "Hit Points" => Ok(Stat::HitPoints),
+                "Damage" => Ok(Stat::Damage),
+                "Armor" => Ok(Stat::Armor),
+                _ => Err(()),
+            }
+        }
+    }
+
+    fn parse(lines: &[&str]) -> Result<HashMap<Stat, i64>, StatBlockError> {
+        parse_stat_block(lines.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn parses_each_stat() {
+        let stats = parse(&["Hit Points: 109", "  Damage:8 ", "", "Armor: 2"]).unwrap();
+
+        assert_eq!(stats.len(), 3);
+        assert_eq!(stats.get(&Stat::HitPoints), Some(&109));
+        assert_eq!(stats.get(&Stat::Damage), Some(&8));
+        assert_eq!(stats.get(&Stat::Armor), Some(&2));
+    }
+
+    #[test]
+    fn unknown_key_names_key_and_line() {
+        let err = parse(&["Hit Points: 109", "Mana: 500"]).unwrap_err();
+
+        assert_eq!(
+            err,
+            StatBlockError::UnknownKey {
+                line_number: 2,
+                key: "Mana".to_string(),
+            }
+        );
+        assert_eq!(err.to_string(), "unknown key \"Mana\" on line 2");
+    }
+
+    #[test]
+    fn duplicate_key() {
+        let err = parse(&["Damage: 8", "", "Damage: 9"]).unwrap_err();
+
+        assert_eq!(
+            err,
+            StatBlockError::DuplicateKey {
+                line_number: 3,
+                key: "Damage".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn non_numeric_value() {
+        let err = parse(&["Damage: lots"]).unwrap_err();
+
+        let StatBlockError::InvalidValue {
+            line_number,
+            key,
+            value,
+            ..
+        } = &err
+        else {
+            panic!("expected an invalid value, got {err:?}");
+        };
+        assert_eq!(*line_number, 1);
+        assert_eq!(key, "Damage");
+        assert_eq!(value, "lots");
+    }
+
+    #[test]
+    fn missing_separator() {
+        let err = parse(&["Damage 8"]).unwrap_err();
+
+        assert_eq!(
+            err,
+            StatBlockError::MissingSeparator {
+                line_number: 1,
+                line: "Damage 8".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn negative_values_are_kept() {
+        let stats = parse(&["Armor: -3"]).unwrap();
+        assert_eq!(stats.get(&Stat::Armor), Some(&-3));
+    }
+
+    #[test]
+    fn validation_rejects_values() {
+        let lines = ["Hit Points: 100", "Armor: -3"].map(String::from);
+        let err = parse_stat_block_with(lines.into_iter(), |_: &Stat, value| {
+            if value < 0 {
+                Err("must not be negative".to_string())
+            } else {
+                Ok(())
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            StatBlockError::OutOfRange {
+                line_number: 2,
+                key: "Armor".to_string(),
+                value: -3,
+                message: "must not be negative".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn empty_block_gives_empty_map() {
+        assert!(parse(&[]).unwrap().is_empty());
+        assert!(parse(&["", "  "]).unwrap().is_empty());
+    }
+}
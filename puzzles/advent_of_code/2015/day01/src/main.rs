@@ -6,6 +6,11 @@ fn main() {
     let input = match read_input().expect("failed to read input") {
         Outcome::Exit => return,
         Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
     };
 
     let directions = parse_input(input);
@@ -13,11 +18,17 @@ fn main() {
     let final_floor = solve_part1(directions.clone());
     println!("Part 1 solution: {final_floor}");
 
-    let first_basement_position = solve_part2(directions);
+    let first_basement_position = solve_part2(directions.clone());
     match first_basement_position {
         Some(pos) => println!("Part 2 solution: {pos}"),
         None => println!("Part 2 solution: Not found"),
     }
+
+    let entries = basement_entries(directions.clone());
+    println!("Basement entries: {entries:?}");
+
+    let (deepest_floor, deepest_pos) = deepest_point(directions);
+    println!("Deepest floor: {deepest_floor} (first reached at position {deepest_pos})");
 }
 
 // -----------------------------------------------------------------------------
@@ -50,6 +61,39 @@ fn solve_part2(directions: Directions) -> Option<usize> {
         .map(|v| v.saturating_add(1))
 }
 
+/// Every position at which Santa enters the basement from floor 0.
+///
+/// Positions are 1-based like in part 2. Going deeper or back up while already in the
+/// basement isn't an entry, but coming back up to floor 0 and going down again is.
+fn basement_entries(directions: Directions) -> Vec<usize> {
+    let mut previous_floor = Floor::default();
+
+    Santa::new(directions)
+        .zip(1..)
+        .filter_map(|(floor, position)| {
+            let entered = !previous_floor.is_basement() && floor.is_basement();
+            previous_floor = floor;
+            entered.then_some(position)
+        })
+        .collect()
+}
+
+/// The lowest floor Santa reaches and the first position he reaches it at.
+///
+/// Santa starts on floor 0 at position 0, so if he never goes below floor 0, including
+/// when there are no directions at all, this is `(Floor(0), 0)`.
+fn deepest_point(directions: Directions) -> (Floor, usize) {
+    Santa::new(directions)
+        .zip(1..)
+        .fold((Floor::default(), 0), |deepest, (floor, position)| {
+            if floor.0 < deepest.0.0 {
+                (floor, position)
+            } else {
+                deepest
+            }
+        })
+}
+
 // -----------------------------------------------------------------------------
 // Internals
 // -----------------------------------------------------------------------------
@@ -142,8 +186,11 @@ mod tests {
         parsed: Directions,
         part1: Floor,
         part2: Option<usize>,
+        basement_entries: Vec<usize>,
+        deepest_point: (Floor, usize),
     }
 
+    #[expect(clippy::too_many_lines, reason = "one entry per example")]
     fn get_test_data() -> Vec<TestData> {
         vec![
             // AoC examples:
@@ -152,54 +199,72 @@ mod tests {
                 parsed: directions!(Up, Up, Down, Down),
                 part1: Floor(0),
                 part2: None,
+                basement_entries: vec![],
+                deepest_point: (Floor(0), 0),
             },
             TestData {
                 input: Input::from("()()"),
                 parsed: directions!(Up, Down, Up, Down),
                 part1: Floor(0),
                 part2: None,
+                basement_entries: vec![],
+                deepest_point: (Floor(0), 0),
             },
             TestData {
                 input: Input::from("((("),
                 parsed: directions!(Up, Up, Up),
                 part1: Floor(3),
                 part2: None,
+                basement_entries: vec![],
+                deepest_point: (Floor(0), 0),
             },
             TestData {
                 input: Input::from("(()(()("),
                 parsed: directions!(Up, Up, Down, Up, Up, Down, Up),
                 part1: Floor(3),
                 part2: None,
+                basement_entries: vec![],
+                deepest_point: (Floor(0), 0),
             },
             TestData {
                 input: Input::from("))((((("),
                 parsed: directions!(Down, Down, Up, Up, Up, Up, Up),
                 part1: Floor(3),
                 part2: Some(1),
+                basement_entries: vec![1],
+                deepest_point: (Floor(-2), 2),
             },
             TestData {
                 input: Input::from("())"),
                 parsed: directions!(Up, Down, Down),
                 part1: Floor(-1),
                 part2: Some(3),
+                basement_entries: vec![3],
+                deepest_point: (Floor(-1), 3),
             },
             TestData {
                 input: Input::from("))("),
                 parsed: directions!(Down, Down, Up),
                 part1: Floor(-1),
                 part2: Some(1),
+                basement_entries: vec![1],
+                deepest_point: (Floor(-2), 2),
             },
             TestData {
                 input: Input::from(")))"),
                 parsed: directions!(Down, Down, Down),
                 part1: Floor(-3),
                 part2: Some(1),
+                basement_entries: vec![1],
+                deepest_point: (Floor(-3), 3),
             },
             TestData {
                 input: Input::from(")())())"),
                 parsed: directions!(Down, Up, Down, Down, Up, Down, Down),
                 part1: Floor(-3),
                 part2: Some(1),
+                basement_entries: vec![1, 3],
+                deepest_point: (Floor(-3), 7),
             },
             // Edge cases:
             TestData {
@@ -207,12 +272,48 @@ mod tests {
                 parsed: Directions::from([]),
                 part1: Floor(0),
                 part2: None,
+                basement_entries: vec![],
+                deepest_point: (Floor(0), 0),
             },
             TestData {
                 input: Input::from("(()test(()"), // some invalid
                 parsed: directions!(Up, Up, Down, Up, Up, Down),
                 part1: Floor(2),
                 part2: None,
+                basement_entries: vec![],
+                deepest_point: (Floor(0), 0),
+            },
+            TestData {
+                input: Input::from(""), // empty
+                parsed: Directions::from([]),
+                part1: Floor(0),
+                part2: None,
+                basement_entries: vec![],
+                deepest_point: (Floor(0), 0),
+            },
+            TestData {
+                input: Input::from("()())()()("), // repeated entries
+                parsed: directions!(Up, Down, Up, Down, Down, Up, Down, Up, Down, Up),
+                part1: Floor(0),
+                part2: Some(5),
+                basement_entries: vec![5, 7, 9],
+                deepest_point: (Floor(-1), 5),
+            },
+            TestData {
+                input: Input::from(")(()))"), // re-entry deeper than the first
+                parsed: directions!(Down, Up, Up, Down, Down, Down),
+                part1: Floor(-2),
+                part2: Some(1),
+                basement_entries: vec![1, 5],
+                deepest_point: (Floor(-2), 6),
+            },
+            TestData {
+                input: Input::from("))()"), // moving within the basement
+                parsed: directions!(Down, Down, Up, Down),
+                part1: Floor(-2),
+                part2: Some(1),
+                basement_entries: vec![1],
+                deepest_point: (Floor(-2), 2),
             },
         ]
     }
@@ -237,4 +338,18 @@ mod tests {
             assert_eq!(solve_part2(data.parsed), data.part2);
         }
     }
+
+    #[test]
+    fn test_basement_entries() {
+        for data in get_test_data() {
+            assert_eq!(basement_entries(data.parsed), data.basement_entries);
+        }
+    }
+
+    #[test]
+    fn test_deepest_point() {
+        for data in get_test_data() {
+            assert_eq!(deepest_point(data.parsed), data.deepest_point);
+        }
+    }
 }
@@ -0,0 +1,533 @@
+use derive_more::Display;
+use input_reader::{Input, Outcome, read_input};
+use statblock::parse_stat_block;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    str::FromStr,
+};
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let boss = parse_input(input);
+
+    let least_mana_normal = solve_part1(boss);
+    match least_mana_normal {
+        Some(mana) => println!("Part 1 solution: {mana}"),
+        None => println!("Part 1 solution: Not found"),
+    }
+
+    let least_mana_hard = solve_part2(boss);
+    match least_mana_hard {
+        Some(mana) => println!("Part 2 solution: {mana}"),
+        None => println!("Part 2 solution: Not found"),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> Boss {
+    let lines = input.lines().map(|line| line.expect("failed to read line"));
+    let stats = parse_stat_block::<BossStat>(lines).expect("failed to parse boss stats");
+
+    let stat = |key: BossStat, name: &str| {
+        let value = stats
+            .get(&key)
+            .unwrap_or_else(|| panic!("boss {name} missing from input"));
+        i32::try_from(*value).unwrap_or_else(|_| panic!("boss {name} out of range"))
+    };
+
+    Boss {
+        hit_points: stat(BossStat::HitPoints, "hit points"),
+        damage: stat(BossStat::Damage, "damage"),
+    }
+}
+
+/// A stat of the boss in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum BossStat {
+    HitPoints,
+    Damage,
+}
+
+impl FromStr for BossStat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Hit Points" => Ok(BossStat::HitPoints),
+            "Damage" => Ok(BossStat::Damage),
+            _ => Err(()),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(boss: Boss) -> Option<u32> {
+    least_mana_to_win(Player::default(), boss, Difficulty::Normal)
+}
+
+fn solve_part2(boss: Boss) -> Option<u32> {
+    least_mana_to_win(Player::default(), boss, Difficulty::Hard)
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// The boss the wizard is fighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Boss {
+    hit_points: i32,
+    damage: i32,
+}
+
+/// The wizard's starting stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Player {
+    hit_points: i32,
+    mana: u32,
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self {
+            hit_points: 50,
+            mana: 500,
+        }
+    }
+}
+
+/// How hard the fight is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Difficulty {
+    Normal,
+    /// The player loses 1 hit point at the start of each of their turns.
+    Hard,
+}
+
+/// What a spell does when cast.
+///
+/// Instant spells take effect immediately, the rest start an effect that lasts
+/// for a number of turns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Effect {
+    /// Deals damage instantly.
+    Damage(i32),
+    /// Deals damage and heals the player instantly.
+    Drain { damage: i32, heal: i32 },
+    /// Increases the player's armor while active.
+    Shield { armor: i32, turns: u8 },
+    /// Deals damage to the boss at the start of each turn while active.
+    Poison { damage: i32, turns: u8 },
+    /// Gives the player mana at the start of each turn while active.
+    Recharge { mana: u32, turns: u8 },
+}
+
+impl Effect {
+    /// How many turns the effect lasts, or `None` if it's instant.
+    fn turns(self) -> Option<u8> {
+        match self {
+            Effect::Damage(_) | Effect::Drain { .. } => None,
+            Effect::Shield { turns, .. }
+            | Effect::Poison { turns, .. }
+            | Effect::Recharge { turns, .. } => Some(turns),
+        }
+    }
+}
+
+/// A spell the wizard can cast.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+#[display("{name}")]
+struct Spell {
+    name: &'static str,
+    mana_cost: u32,
+    effect: Effect,
+}
+
+/// All the spells the wizard knows.
+const SPELLS: [Spell; 5] = [
+    Spell {
+        name: "Magic Missile",
+        mana_cost: 53,
+        effect: Effect::Damage(4),
+    },
+    Spell {
+        name: "Drain",
+        mana_cost: 73,
+        effect: Effect::Drain { damage: 2, heal: 2 },
+    },
+    Spell {
+        name: "Shield",
+        mana_cost: 113,
+        effect: Effect::Shield { armor: 7, turns: 6 },
+    },
+    Spell {
+        name: "Poison",
+        mana_cost: 173,
+        effect: Effect::Poison {
+            damage: 3,
+            turns: 6,
+        },
+    },
+    Spell {
+        name: "Recharge",
+        mana_cost: 229,
+        effect: Effect::Recharge {
+            mana: 101,
+            turns: 5,
+        },
+    },
+];
+
+/// The state of the fight at the start of one of the player's turns.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct GameState {
+    player_hp: i32,
+    boss_hp: i32,
+    mana: u32,
+    armor: i32,
+    /// Active effects and their remaining timers.
+    active_effects: Vec<(Effect, u8)>,
+}
+
+impl GameState {
+    fn new(player: Player, boss: Boss) -> Self {
+        Self {
+            player_hp: player.hit_points,
+            boss_hp: boss.hit_points,
+            mana: player.mana,
+            armor: 0,
+            active_effects: Vec::new(),
+        }
+    }
+
+    fn is_boss_defeated(&self) -> bool {
+        self.boss_hp <= 0
+    }
+
+    fn is_player_defeated(&self) -> bool {
+        self.player_hp <= 0
+    }
+
+    /// Applies all active effects, then decreases their timers.
+    ///
+    /// Effects whose timer reaches 0 wear off.
+    fn apply_effects(&mut self) {
+        self.armor = 0;
+
+        for (effect, timer) in &mut self.active_effects {
+            match *effect {
+                Effect::Shield { armor, .. } => self.armor = armor,
+                Effect::Poison { damage, .. } => {
+                    self.boss_hp = self.boss_hp.saturating_sub(damage);
+                }
+                Effect::Recharge { mana, .. } => self.mana = self.mana.saturating_add(mana),
+                Effect::Damage(_) | Effect::Drain { .. } => {} // instant only
+            }
+
+            *timer = timer.saturating_sub(1);
+        }
+
+        self.active_effects.retain(|&(_, timer)| timer > 0);
+    }
+
+    /// Whether the spell is affordable and its effect isn't already active.
+    fn can_cast(&self, spell: &Spell) -> bool {
+        self.mana >= spell.mana_cost
+            && !self
+                .active_effects
+                .iter()
+                .any(|(effect, _)| *effect == spell.effect)
+    }
+
+    fn cast(&mut self, spell: &Spell) {
+        self.mana = self.mana.saturating_sub(spell.mana_cost);
+
+        match spell.effect {
+            Effect::Damage(damage) => self.boss_hp = self.boss_hp.saturating_sub(damage),
+            Effect::Drain { damage, heal } => {
+                self.boss_hp = self.boss_hp.saturating_sub(damage);
+                self.player_hp = self.player_hp.saturating_add(heal);
+            }
+            effect => {
+                if let Some(turns) = effect.turns() {
+                    self.active_effects.push((effect, turns));
+                }
+            }
+        }
+    }
+
+    fn boss_attacks(&mut self, boss: Boss) {
+        let damage = boss.damage.saturating_sub(self.armor).max(1);
+        self.player_hp = self.player_hp.saturating_sub(damage);
+    }
+
+    /// Plays out the player's turn casting `spell`, followed by the boss' turn.
+    ///
+    /// Returns `None` if the spell can't be cast or the player is defeated,
+    /// otherwise the state at the start of the next player turn and the mana
+    /// spent. The returned state may have the boss defeated, and if the boss
+    /// died from an effect before the spell was cast, no mana was spent.
+    fn play_round(&self, spell: &Spell, boss: Boss, difficulty: Difficulty) -> Option<(Self, u32)> {
+        let mut state = self.clone();
+
+        // Player turn
+        if difficulty == Difficulty::Hard {
+            state.player_hp = state.player_hp.saturating_sub(1);
+            if state.is_player_defeated() {
+                return None;
+            }
+        }
+
+        state.apply_effects();
+        if state.is_boss_defeated() {
+            return Some((state, 0));
+        }
+
+        if !state.can_cast(spell) {
+            return None;
+        }
+        state.cast(spell);
+        if state.is_boss_defeated() {
+            return Some((state, spell.mana_cost));
+        }
+
+        // Boss turn
+        state.apply_effects();
+        if state.is_boss_defeated() {
+            return Some((state, spell.mana_cost));
+        }
+
+        state.boss_attacks(boss);
+        if state.is_player_defeated() {
+            return None;
+        }
+
+        Some((state, spell.mana_cost))
+    }
+}
+
+/// Finds the least amount of mana the player can spend and still win the fight.
+///
+/// Fights are always explored in order of mana spent, so the first fight found
+/// where the boss is defeated is the cheapest one.
+fn least_mana_to_win(player: Player, boss: Boss, difficulty: Difficulty) -> Option<u32> {
+    let mut fights = BinaryHeap::new();
+    let mut seen = HashSet::new();
+
+    fights.push(Reverse((0, GameState::new(player, boss))));
+
+    while let Some(Reverse((mana_spent, state))) = fights.pop() {
+        if state.is_boss_defeated() {
+            return Some(mana_spent);
+        }
+
+        if !seen.insert(state.clone()) {
+            continue;
+        }
+
+        for spell in &SPELLS {
+            if let Some((next_state, cost)) = state.play_round(spell, boss, difficulty) {
+                let mana_spent = mana_spent.saturating_add(cost);
+                fights.push(Reverse((mana_spent, next_state)));
+            }
+        }
+    }
+
+    None
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestData {
+        input: Input,
+        parsed: Boss,
+        part1: Option<u32>,
+        part2: Option<u32>,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // Boss stats from an actual puzzle input:
+            TestData {
+                input: Input::from(vec!["Hit Points: 51", "Damage: 9"]),
+                parsed: Boss {
+                    hit_points: 51,
+                    damage: 9,
+                },
+                part1: Some(900),
+                part2: Some(1216),
+            },
+        ]
+    }
+
+    fn spell(name: &str) -> &'static Spell {
+        SPELLS
+            .iter()
+            .find(|spell| spell.to_string() == name)
+            .expect("unknown spell")
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        for data in get_test_data() {
+            assert_eq!(solve_part2(data.parsed), data.part2);
+        }
+    }
+
+    #[test]
+    fn test_first_example_fight() {
+        let player = Player {
+            hit_points: 10,
+            mana: 250,
+        };
+        let boss = Boss {
+            hit_points: 13,
+            damage: 8,
+        };
+
+        let state = GameState::new(player, boss);
+        let (state, _) = state
+            .play_round(spell("Poison"), boss, Difficulty::Normal)
+            .expect("player should survive");
+        assert_eq!(state.player_hp, 2);
+        assert_eq!(state.boss_hp, 10);
+        assert_eq!(state.mana, 77);
+
+        let (state, _) = state
+            .play_round(spell("Magic Missile"), boss, Difficulty::Normal)
+            .expect("player should survive");
+        assert!(state.is_boss_defeated());
+
+        assert_eq!(
+            least_mana_to_win(player, boss, Difficulty::Normal),
+            Some(173 + 53)
+        );
+    }
+
+    #[test]
+    fn test_second_example_fight() {
+        let player = Player {
+            hit_points: 10,
+            mana: 250,
+        };
+        let boss = Boss {
+            hit_points: 14,
+            damage: 8,
+        };
+
+        let mut state = GameState::new(player, boss);
+        for name in ["Recharge", "Shield", "Drain", "Poison", "Magic Missile"] {
+            assert!(!state.is_boss_defeated());
+            (state, _) = state
+                .play_round(spell(name), boss, Difficulty::Normal)
+                .expect("player should survive");
+        }
+
+        assert!(state.is_boss_defeated());
+        assert_eq!(state.player_hp, 1);
+    }
+
+    #[test]
+    fn test_active_effect_cannot_be_recast() {
+        let player = Player::default();
+        let boss = Boss {
+            hit_points: 100,
+            damage: 1,
+        };
+
+        let (state, _) = GameState::new(player, boss)
+            .play_round(spell("Poison"), boss, Difficulty::Normal)
+            .expect("player should survive");
+        assert!(
+            state
+                .play_round(spell("Poison"), boss, Difficulty::Normal)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_hard_mode_drains_player() {
+        let player = Player {
+            hit_points: 1,
+            mana: 500,
+        };
+        let boss = Boss {
+            hit_points: 4,
+            damage: 1,
+        };
+
+        assert_eq!(
+            least_mana_to_win(player, boss, Difficulty::Normal),
+            Some(53)
+        );
+        assert_eq!(least_mana_to_win(player, boss, Difficulty::Hard), None);
+    }
+
+    #[test]
+    fn test_win_by_poison_tick_costs_no_extra_mana() {
+        let player = Player {
+            hit_points: 3,
+            mana: 250,
+        };
+        let boss = Boss {
+            hit_points: 14,
+            damage: 2,
+        };
+
+        let mut state = GameState::new(player, boss);
+        for name in ["Poison", "Drain"] {
+            (state, _) = state
+                .play_round(spell(name), boss, Difficulty::Normal)
+                .expect("player should survive");
+        }
+        assert_eq!(state.boss_hp, 3);
+
+        // Poison finishes the boss before anything is cast
+        let (state, cost) = state
+            .play_round(spell("Magic Missile"), boss, Difficulty::Normal)
+            .expect("boss should be defeated");
+        assert!(state.is_boss_defeated());
+        assert_eq!(cost, 0);
+
+        assert_eq!(
+            least_mana_to_win(player, boss, Difficulty::Normal),
+            Some(173 + 73)
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use derive_more::From;
+use input_reader::{Input, Outcome, read_input};
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let program = parse_input(input);
+
+    let register_b = solve_part1(program.clone());
+    println!("Part 1 solution: {register_b}");
+
+    let register_b = solve_part2(program);
+    println!("Part 2 solution: {register_b}");
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> Program {
+    input
+        .non_blank_lines()
+        .map(|line| line.expect("failed to read line"))
+        .map(|line| parse_instruction(&line))
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn parse_instruction(line: &str) -> Instruction {
+    let (name, operands) = line
+        .trim()
+        .split_once(' ')
+        .expect("instruction should have operands");
+    let mut operands = operands.split(',').map(str::trim);
+    let mut next_operand = || operands.next().expect("missing operand");
+
+    match name {
+        "hlf" => Instruction::Half(parse_register(next_operand())),
+        "tpl" => Instruction::Triple(parse_register(next_operand())),
+        "inc" => Instruction::Increment(parse_register(next_operand())),
+        "jmp" => Instruction::Jump(parse_offset(next_operand())),
+        "jie" => {
+            let register = parse_register(next_operand());
+            Instruction::JumpIfEven(register, parse_offset(next_operand()))
+        }
+        "jio" => {
+            let register = parse_register(next_operand());
+            Instruction::JumpIfOne(register, parse_offset(next_operand()))
+        }
+        _ => panic!("unknown instruction: {name}"),
+    }
+}
+
+fn parse_register(operand: &str) -> Register {
+    match operand {
+        "a" => Register::A,
+        "b" => Register::B,
+        _ => panic!("unknown register: {operand}"),
+    }
+}
+
+fn parse_offset(operand: &str) -> isize {
+    operand.parse().expect("failed to parse offset")
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(program: Program) -> u64 {
+    let mut cpu = Cpu::new(program, [0, 0]);
+    cpu.run();
+    cpu.register(Register::B)
+}
+
+fn solve_part2(program: Program) -> u64 {
+    let mut cpu = Cpu::new(program, [1, 0]);
+    cpu.run();
+    cpu.register(Register::B)
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// The program running on Jane Marie's computer.
+#[derive(Debug, Clone, From, PartialEq, Eq)]
+struct Program(Vec<Instruction>);
+
+/// The two registers of the computer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Register {
+    A,
+    B,
+}
+
+/// An instruction the computer understands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    /// `hlf r` sets register `r` to half its current value.
+    Half(Register),
+    /// `tpl r` sets register `r` to triple its current value.
+    Triple(Register),
+    /// `inc r` increments register `r`.
+    Increment(Register),
+    /// `jmp offset` jumps relative to itself.
+    Jump(isize),
+    /// `jie r, offset` jumps if register `r` is even.
+    JumpIfEven(Register, isize),
+    /// `jio r, offset` jumps if register `r` is one (not odd!).
+    JumpIfOne(Register, isize),
+}
+
+/// The computer, ready to run a program.
+#[derive(Debug)]
+struct Cpu {
+    registers: [u64; 2],
+    pc: usize,
+    program: Vec<Instruction>,
+}
+
+impl Cpu {
+    fn new(program: Program, registers: [u64; 2]) -> Self {
+        Self {
+            registers,
+            pc: 0,
+            program: program.0,
+        }
+    }
+
+    fn register(&self, register: Register) -> u64 {
+        let [a, b] = self.registers;
+        match register {
+            Register::A => a,
+            Register::B => b,
+        }
+    }
+
+    fn register_mut(&mut self, register: Register) -> &mut u64 {
+        let [a, b] = &mut self.registers;
+        match register {
+            Register::A => a,
+            Register::B => b,
+        }
+    }
+
+    /// Moves the program counter by `offset`.
+    ///
+    /// Jumping before the first instruction moves the counter past the end of
+    /// the program, which halts it just like jumping past the last one does.
+    fn jump(&mut self, offset: isize) {
+        self.pc = self.pc.checked_add_signed(offset).unwrap_or(usize::MAX);
+    }
+
+    /// Executes one instruction.
+    ///
+    /// Returns `false` once the program counter is outside the program and
+    /// the computer has halted.
+    fn step(&mut self) -> bool {
+        let Some(&instruction) = self.program.get(self.pc) else {
+            return false;
+        };
+
+        match instruction {
+            Instruction::Half(r) => *self.register_mut(r) /= 2,
+            Instruction::Triple(r) => {
+                let value = self.register_mut(r);
+                *value = value.saturating_mul(3);
+            }
+            Instruction::Increment(r) => {
+                let value = self.register_mut(r);
+                *value = value.saturating_add(1);
+            }
+            Instruction::Jump(offset) => return self.jump_and_continue(offset),
+            Instruction::JumpIfEven(r, offset) => {
+                if self.register(r).is_multiple_of(2) {
+                    return self.jump_and_continue(offset);
+                }
+            }
+            Instruction::JumpIfOne(r, offset) => {
+                if self.register(r) == 1 {
+                    return self.jump_and_continue(offset);
+                }
+            }
+        }
+
+        self.jump_and_continue(1)
+    }
+
+    fn jump_and_continue(&mut self, offset: isize) -> bool {
+        self.jump(offset);
+        self.pc < self.program.len()
+    }
+
+    /// Runs the program until the computer halts.
+    fn run(&mut self) {
+        while self.step() {}
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestData {
+        input: Input,
+        parsed: Program,
+        part1: u64,
+        part2: u64,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // AoC example:
+            TestData {
+                input: Input::from(vec!["inc a", "jio a, +2", "tpl a", "inc a"]),
+                parsed: Program(vec![
+                    Instruction::Increment(Register::A),
+                    Instruction::JumpIfOne(Register::A, 2),
+                    Instruction::Triple(Register::A),
+                    Instruction::Increment(Register::A),
+                ]),
+                part1: 0,
+                part2: 0,
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from(vec!["inc b", "jie a, -2", "inc b", ""]),
+                parsed: Program(vec![
+                    Instruction::Increment(Register::B),
+                    Instruction::JumpIfEven(Register::A, -2),
+                    Instruction::Increment(Register::B),
+                ]),
+                part1: 1, // a = 0 is even, jumping before the start halts
+                part2: 2, // a = 1 is odd, falls through
+            },
+        ]
+    }
+
+    fn run(instructions: Vec<Instruction>, registers: [u64; 2]) -> Cpu {
+        let mut cpu = Cpu::new(Program(instructions), registers);
+        cpu.run();
+        cpu
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        for data in get_test_data() {
+            assert_eq!(solve_part2(data.parsed), data.part2);
+        }
+    }
+
+    #[test]
+    fn test_example_sets_register_a_to_two() {
+        let program = get_test_data().remove(0).parsed;
+        let mut cpu = Cpu::new(program, [0, 0]);
+        cpu.run();
+        assert_eq!(cpu.register(Register::A), 2);
+    }
+
+    #[test]
+    fn test_half() {
+        let cpu = run(vec![Instruction::Half(Register::A)], [7, 8]);
+        assert_eq!(cpu.registers, [3, 8]);
+    }
+
+    #[test]
+    fn test_triple() {
+        let cpu = run(vec![Instruction::Triple(Register::B)], [7, 8]);
+        assert_eq!(cpu.registers, [7, 24]);
+    }
+
+    #[test]
+    fn test_increment() {
+        let cpu = run(vec![Instruction::Increment(Register::A)], [7, 8]);
+        assert_eq!(cpu.registers, [8, 8]);
+    }
+
+    #[test]
+    fn test_jump() {
+        let mut cpu = Cpu::new(
+            Program(vec![
+                Instruction::Jump(2),
+                Instruction::Increment(Register::A),
+                Instruction::Jump(-2),
+            ]),
+            [0, 0],
+        );
+
+        assert!(cpu.step());
+        assert_eq!(cpu.pc, 2);
+        assert!(cpu.step());
+        assert_eq!(cpu.pc, 0);
+        assert!(!Cpu::new(Program(vec![Instruction::Jump(-1)]), [0, 0]).step());
+    }
+
+    #[test]
+    fn test_jump_if_even() {
+        let program = vec![
+            Instruction::JumpIfEven(Register::A, 2),
+            Instruction::Increment(Register::B),
+        ];
+
+        assert_eq!(run(program.clone(), [4, 0]).registers, [4, 0]);
+        assert_eq!(run(program, [3, 0]).registers, [3, 1]);
+    }
+
+    #[test]
+    fn test_jump_if_one() {
+        let program = vec![
+            Instruction::JumpIfOne(Register::B, 2),
+            Instruction::Increment(Register::A),
+        ];
+
+        assert_eq!(run(program.clone(), [0, 1]).registers, [0, 1]);
+        assert_eq!(run(program, [0, 3]).registers, [1, 3]);
+    }
+
+    #[test]
+    fn test_step_halts_outside_program() {
+        let mut cpu = Cpu::new(Program(vec![Instruction::Increment(Register::A)]), [0, 0]);
+        assert!(!cpu.step());
+        assert!(!cpu.step());
+        assert_eq!(cpu.registers, [1, 0]);
+    }
+}
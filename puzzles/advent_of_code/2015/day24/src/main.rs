@@ -0,0 +1,201 @@
+use derive_more::{Deref, From};
+use input_reader::{Input, Outcome, read_input};
+use itertools::Itertools;
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let packages = parse_input(input);
+
+    let three_groups = solve_part1(&packages);
+    match three_groups {
+        Some(entanglement) => println!("Part 1 solution: {entanglement}"),
+        None => println!("Part 1 solution: Not found"),
+    }
+
+    let four_groups = solve_part2(&packages);
+    match four_groups {
+        Some(entanglement) => println!("Part 2 solution: {entanglement}"),
+        None => println!("Part 2 solution: Not found"),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> Packages {
+    input
+        .parse_lines::<u32>()
+        .expect("failed to parse weights")
+        .into()
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(packages: &Packages) -> Option<u128> {
+    ideal_passenger_compartment(packages, 3).map(|group| quantum_entanglement(&group))
+}
+
+fn solve_part2(packages: &Packages) -> Option<u128> {
+    ideal_passenger_compartment(packages, 4).map(|group| quantum_entanglement(&group))
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// The weights of the packages Santa needs to load onto the sleigh.
+#[derive(Debug, Clone, Deref, From, PartialEq, Eq)]
+struct Packages(Vec<u32>);
+
+/// The product of the weights of the packages in a group.
+fn quantum_entanglement(group: &[u32]) -> u128 {
+    group.iter().map(|&weight| u128::from(weight)).product()
+}
+
+/// Finds the packages to put in the passenger compartment.
+///
+/// The packages must be split into `groups` groups of equal weight. The group
+/// in the passenger compartment needs as few packages as possible, and among
+/// those the one with the smallest quantum entanglement is picked.
+///
+/// Group sizes are tried from smallest to largest, so once a size that works
+/// is found, no larger groups are ever looked at. Within a size, groups are
+/// tried in order of quantum entanglement, so only the best groups need the
+/// (slower) check that the remaining packages can be split evenly.
+fn ideal_passenger_compartment(packages: &[u32], groups: usize) -> Option<Vec<u32>> {
+    let group_weight = weight_per_group(packages, groups)?;
+
+    (1..=packages.len()).find_map(|size| {
+        packages
+            .iter()
+            .copied()
+            .combinations(size)
+            .filter(|group| group.iter().sum::<u32>() == group_weight)
+            .sorted_by_key(|group| quantum_entanglement(group))
+            .find(|group| {
+                let rest = remaining_packages(packages, group);
+                can_split_evenly(&rest, groups.saturating_sub(1), group_weight)
+            })
+    })
+}
+
+/// The weight each group must have, if the packages can be split evenly.
+fn weight_per_group(packages: &[u32], groups: usize) -> Option<u32> {
+    let total_weight: u32 = packages.iter().sum();
+    let groups = u32::try_from(groups).ok()?;
+
+    if groups == 0 || !total_weight.is_multiple_of(groups) {
+        return None;
+    }
+
+    total_weight.checked_div(groups)
+}
+
+/// The packages left over once `group` has been taken out.
+///
+/// Packages are removed one at a time, so packages of equal weight are kept
+/// apart correctly.
+fn remaining_packages(packages: &[u32], group: &[u32]) -> Vec<u32> {
+    let mut rest = packages.to_vec();
+
+    for weight in group {
+        if let Some(pos) = rest.iter().position(|w| w == weight) {
+            rest.remove(pos);
+        }
+    }
+
+    rest
+}
+
+/// Checks whether `packages` can be split into `groups` groups of `group_weight`.
+fn can_split_evenly(packages: &[u32], groups: usize, group_weight: u32) -> bool {
+    match groups {
+        0 => packages.is_empty(),
+        1 => packages.iter().sum::<u32>() == group_weight,
+        _ => (1..=packages.len()).any(|size| {
+            packages
+                .iter()
+                .copied()
+                .combinations(size)
+                .filter(|group| group.iter().sum::<u32>() == group_weight)
+                .any(|group| {
+                    let rest = remaining_packages(packages, &group);
+                    can_split_evenly(&rest, groups.saturating_sub(1), group_weight)
+                })
+        }),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestData {
+        input: Input,
+        parsed: Packages,
+        part1: Option<u128>,
+        part2: Option<u128>,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // AoC example:
+            TestData {
+                input: Input::from(vec!["1", "2", "3", "4", "5", "7", "8", "9", "10", "11"]),
+                parsed: Packages(vec![1, 2, 3, 4, 5, 7, 8, 9, 10, 11]),
+                part1: Some(99),
+                part2: Some(44),
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from(vec!["1", "2", "", "3"]),
+                parsed: Packages(vec![1, 2, 3]),
+                part1: None, // 3 is heavier than a third of the weight
+                part2: None, // 6 can't be split in four
+            },
+            TestData {
+                input: Input::from(vec!["1", "6", "7", "8", "9", "11"]),
+                parsed: Packages(vec![1, 6, 7, 8, 9, 11]),
+                part1: None, // [6, 8] weighs 14, but the rest can't be split
+                part2: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(&data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        for data in get_test_data() {
+            assert_eq!(solve_part2(&data.parsed), data.part2);
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use derive_more::Display;
+use input_reader::{Input, Outcome, read_input};
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let position = parse_input(input);
+
+    let code = solve_part1(position);
+    println!("Part 1 solution: {code}");
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> Position {
+    let text = input
+        .lines()
+        .map(|line| line.expect("failed to read line"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut words = text.split_whitespace();
+    let mut row = None;
+    let mut column = None;
+
+    while let Some(word) = words.next() {
+        let target = match word {
+            "row" => &mut row,
+            "column" => &mut column,
+            _ => continue,
+        };
+
+        let value = words
+            .next()
+            .map(|value| value.trim_end_matches([',', '.']))
+            .expect("missing number after row or column");
+        *target = Some(value.parse().expect("failed to parse number"));
+    }
+
+    Position {
+        row: row.expect("row missing from input"),
+        column: column.expect("column missing from input"),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(position: Position) -> Code {
+    code_at(position)
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// A position on the code sheet in the manual, both starting at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    row: u64,
+    column: u64,
+}
+
+impl Position {
+    /// The order in which the code at this position is filled in, starting at 1.
+    ///
+    /// Codes are filled in diagonal by diagonal, each going from the bottom left
+    /// to the top right. The `n`th diagonal starts at row `n`, so the diagonal
+    /// of this position is `row + column - 1`. All the diagonals before it hold
+    /// `1 + 2 + ... + (n - 1)` codes, and within its own diagonal the position
+    /// is the `column`th code.
+    fn order(self) -> u64 {
+        let diagonal = self
+            .row
+            .checked_add(self.column)
+            .and_then(|sum| sum.checked_sub(1))
+            .expect("position should be on the sheet");
+        let codes_before = diagonal
+            .checked_mul(diagonal.saturating_sub(1))
+            .map(|product| product / 2)
+            .expect("position too far down the sheet");
+
+        codes_before
+            .checked_add(self.column)
+            .expect("position too far down the sheet")
+    }
+}
+
+/// A code from the code sheet.
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+struct Code(u64);
+
+/// Generates codes in the order they are filled into the sheet.
+#[derive(Debug)]
+struct CodeGenerator {
+    next_code: Code,
+}
+
+impl Default for CodeGenerator {
+    fn default() -> Self {
+        Self {
+            next_code: Code(20_151_125),
+        }
+    }
+}
+
+impl Iterator for CodeGenerator {
+    type Item = Code;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let code = self.next_code;
+        self.next_code = Code(code.0.checked_mul(252_533)? % 33_554_393);
+        Some(code)
+    }
+}
+
+/// Finds the code at `position` by generating every code up to it.
+fn code_at(position: Position) -> Code {
+    let skip = position.order().saturating_sub(1);
+    let skip = usize::try_from(skip).expect("position too far down the sheet");
+
+    CodeGenerator::default()
+        .nth(skip)
+        .expect("codes should never run out")
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestData {
+        input: Input,
+        parsed: Position,
+        part1: Code,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // AoC examples, in the format of the puzzle input:
+            TestData {
+                input: Input::from(
+                    "To continue, please consult the code grid in the manual.  \
+                     Enter the code at row 2, column 3.",
+                ),
+                parsed: Position { row: 2, column: 3 },
+                part1: Code(16_929_656),
+            },
+            TestData {
+                input: Input::from(
+                    "To continue, please consult the code grid in the manual.  \
+                     Enter the code at row 3, column 2.",
+                ),
+                parsed: Position { row: 3, column: 2 },
+                part1: Code(8_057_251),
+            },
+            TestData {
+                input: Input::from(vec![
+                    "To continue, please consult the code grid in the manual.",
+                    "Enter the code at row 6, column 6.",
+                ]),
+                parsed: Position { row: 6, column: 6 },
+                part1: Code(27_995_004),
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from("Enter the code at row 1, column 1."),
+                parsed: Position { row: 1, column: 1 },
+                part1: Code(20_151_125),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_order_follows_diagonals() {
+        // The order numbers from the top left corner of the sheet in the puzzle:
+        let sheet = [
+            [1, 3, 6, 10, 15, 21],
+            [2, 5, 9, 14, 20, 0],
+            [4, 8, 13, 19, 0, 0],
+            [7, 12, 18, 0, 0, 0],
+            [11, 17, 0, 0, 0, 0],
+            [16, 0, 0, 0, 0, 0],
+        ];
+
+        for (row, orders) in (1..).zip(sheet) {
+            for (column, order) in (1..).zip(orders) {
+                if order != 0 {
+                    assert_eq!(Position { row, column }.order(), order);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_first_codes() {
+        let codes: Vec<_> = CodeGenerator::default().take(3).collect();
+        assert_eq!(
+            codes,
+            vec![Code(20_151_125), Code(31_916_031), Code(18_749_137)]
+        );
+    }
+}
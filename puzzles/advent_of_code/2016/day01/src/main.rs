@@ -0,0 +1,298 @@
+use derive_more::{Deref, DerefMut};
+use input_reader::{Input, Outcome, read_input};
+use std::collections::{HashSet, VecDeque};
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let instructions = parse_input(input);
+
+    let distance_to_hq = solve_part1(instructions.clone());
+    println!("Part 1 solution: {distance_to_hq}");
+
+    let distance_to_real_hq = solve_part2(instructions);
+    match distance_to_real_hq {
+        Some(distance) => println!("Part 2 solution: {distance}"),
+        None => println!("Part 2 solution: Not found"),
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> Instructions {
+    input
+        .lines()
+        .map(|line| line.expect("failed to read line"))
+        .flat_map(|line| {
+            line.split(',')
+                .map(str::trim)
+                .filter(|instruction| !instruction.is_empty())
+                .map(parse_instruction)
+                .collect::<Vec<_>>()
+        })
+        .collect::<VecDeque<_>>()
+        .into()
+}
+
+fn parse_instruction(instruction: &str) -> Instruction {
+    let (turn, blocks) = instruction.split_at(1);
+
+    let turn = match turn {
+        "L" => Turn::Left,
+        "R" => Turn::Right,
+        _ => panic!("unknown turn: {turn}"),
+    };
+    let blocks = blocks.parse().expect("failed to parse blocks");
+
+    Instruction { turn, blocks }
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(instructions: Instructions) -> u32 {
+    Walker::new(instructions)
+        .last()
+        .unwrap_or_default()
+        .blocks_away()
+}
+
+fn solve_part2(instructions: Instructions) -> Option<u32> {
+    let mut visited = HashSet::from([Location::default()]);
+
+    Walker::new(instructions)
+        .find(|&location| !visited.insert(location))
+        .map(Location::blocks_away)
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// The instructions from the recovered Easter Bunny Recruiting Document.
+#[derive(Debug, Clone, Deref, DerefMut, PartialEq, Eq)]
+struct Instructions(VecDeque<Instruction>); // VecDeque for FIFO
+
+impl<T> From<T> for Instructions
+where
+    VecDeque<Instruction>: From<T>,
+{
+    fn from(value: T) -> Self {
+        Instructions(VecDeque::from(value))
+    }
+}
+
+/// Turn 90 degrees, then walk forward the given number of blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Instruction {
+    turn: Turn,
+    blocks: u32,
+}
+
+/// Which way to turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Turn {
+    Left,
+    Right,
+}
+
+/// The direction being faced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Facing {
+    #[default]
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Facing {
+    fn turn(self, turn: Turn) -> Self {
+        match (self, turn) {
+            (Facing::North, Turn::Left) | (Facing::South, Turn::Right) => Facing::West,
+            (Facing::North, Turn::Right) | (Facing::South, Turn::Left) => Facing::East,
+            (Facing::East, Turn::Left) | (Facing::West, Turn::Right) => Facing::North,
+            (Facing::East, Turn::Right) | (Facing::West, Turn::Left) => Facing::South,
+        }
+    }
+}
+
+/// A street intersection in the city grid, relative to where we were airdropped.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+struct Location {
+    x: i32,
+    y: i32,
+}
+
+impl Location {
+    fn step(self, facing: Facing) -> Self {
+        let Location { x, y } = self;
+
+        match facing {
+            Facing::North => Location {
+                x,
+                y: y.saturating_add(1),
+            },
+            Facing::East => Location {
+                x: x.saturating_add(1),
+                y,
+            },
+            Facing::South => Location {
+                x,
+                y: y.saturating_sub(1),
+            },
+            Facing::West => Location {
+                x: x.saturating_sub(1),
+                y,
+            },
+        }
+    }
+
+    /// The shortest path to this location in blocks, only walking the streets.
+    fn blocks_away(self) -> u32 {
+        self.x.unsigned_abs().saturating_add(self.y.unsigned_abs())
+    }
+}
+
+/// Someone following the instructions one block at a time.
+#[derive(Debug)]
+struct Walker {
+    location: Location,
+    facing: Facing,
+    blocks_left: u32,
+    instructions: Instructions,
+}
+
+impl Walker {
+    fn new(instructions: Instructions) -> Self {
+        Self {
+            location: Location::default(),
+            facing: Facing::default(),
+            blocks_left: 0,
+            instructions,
+        }
+    }
+}
+
+impl Iterator for Walker {
+    type Item = Location;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.blocks_left == 0 {
+            let instruction = self.instructions.pop_front()?;
+            self.facing = self.facing.turn(instruction.turn);
+            self.blocks_left = instruction.blocks;
+        }
+
+        self.blocks_left = self.blocks_left.saturating_sub(1);
+        self.location = self.location.step(self.facing);
+
+        Some(self.location)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! instructions {
+        ($($turn:ident $blocks:literal),+ $(,)?) => {
+            Instructions::from(vec![$(Instruction {
+                turn: Turn::$turn,
+                blocks: $blocks,
+            }),+])
+        };
+    }
+
+    struct TestData {
+        input: Input,
+        parsed: Instructions,
+        part1: u32,
+        part2: Option<u32>,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // AoC examples:
+            TestData {
+                input: Input::from("R2, L3"),
+                parsed: instructions!(Right 2, Left 3),
+                part1: 5,
+                part2: None,
+            },
+            TestData {
+                input: Input::from("R2, R2, R2"),
+                parsed: instructions!(Right 2, Right 2, Right 2),
+                part1: 2,
+                part2: None,
+            },
+            TestData {
+                input: Input::from("R5, L5, R5, R3"),
+                parsed: instructions!(Right 5, Left 5, Right 5, Right 3),
+                part1: 12,
+                part2: None,
+            },
+            TestData {
+                input: Input::from("R8, R4, R4, R8"),
+                parsed: instructions!(Right 8, Right 4, Right 4, Right 8),
+                part1: 8,
+                part2: Some(4),
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from(""), // no instructions
+                parsed: Instructions::from([]),
+                part1: 0,
+                part2: None,
+            },
+            TestData {
+                input: Input::from(vec!["R1, R1,", "R1, R1"]), // back to start
+                parsed: instructions!(Right 1, Right 1, Right 1, Right 1),
+                part1: 0,
+                part2: Some(0),
+            },
+            TestData {
+                input: Input::from("L0, L10"), // turning in place
+                parsed: instructions!(Left 0, Left 10),
+                part1: 10,
+                part2: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        for data in get_test_data() {
+            assert_eq!(solve_part2(data.parsed), data.part2);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use derive_more::{Deref, From};
+use input_reader::{Input, Outcome, read_input};
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let instructions = parse_input(input);
+
+    let imagined_code = solve_part1(&instructions);
+    println!("Part 1 solution: {imagined_code}");
+
+    let actual_code = solve_part2(&instructions);
+    println!("Part 2 solution: {actual_code}");
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> Instructions {
+    input
+        .non_blank_lines()
+        .map(|line| line.expect("failed to read line"))
+        .map(|line| {
+            line.chars()
+                .filter_map(|c| match c {
+                    'U' => Some(Move::Up),
+                    'D' => Some(Move::Down),
+                    'L' => Some(Move::Left),
+                    'R' => Some(Move::Right),
+                    _ => None, // ignore unknown chars
+                })
+                .collect()
+        })
+        .collect::<Vec<_>>()
+        .into()
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(instructions: &Instructions) -> String {
+    Keypad::imagined().bathroom_code(instructions)
+}
+
+fn solve_part2(instructions: &Instructions) -> String {
+    Keypad::actual().bathroom_code(instructions)
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// The instructions for finding the bathroom code, one line per button.
+#[derive(Debug, Clone, Deref, From, PartialEq, Eq)]
+struct Instructions(Vec<Vec<Move>>);
+
+/// A move of the finger to an adjacent button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Move {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Where the finger is on the keypad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    row: usize,
+    column: usize,
+}
+
+impl Position {
+    /// The position one step away, or `None` if it's outside the layout.
+    fn step(self, step: Move) -> Option<Self> {
+        let Position { row, column } = self;
+
+        Some(match step {
+            Move::Up => Position {
+                row: row.checked_sub(1)?,
+                column,
+            },
+            Move::Down => Position {
+                row: row.checked_add(1)?,
+                column,
+            },
+            Move::Left => Position {
+                row,
+                column: column.checked_sub(1)?,
+            },
+            Move::Right => Position {
+                row,
+                column: column.checked_add(1)?,
+            },
+        })
+    }
+}
+
+/// A keypad on the bathroom door.
+///
+/// The layout is a grid where `None` marks a spot without a button.
+#[derive(Debug)]
+struct Keypad {
+    layout: &'static [&'static [Option<char>]],
+    start: Position,
+}
+
+impl Keypad {
+    /// The keypad we imagine is on the door.
+    ///
+    /// ```text
+    /// 1 2 3
+    /// 4 5 6
+    /// 7 8 9
+    /// ```
+    fn imagined() -> Self {
+        Self {
+            layout: &[
+                &[Some('1'), Some('2'), Some('3')],
+                &[Some('4'), Some('5'), Some('6')],
+                &[Some('7'), Some('8'), Some('9')],
+            ],
+            start: Position { row: 1, column: 1 },
+        }
+    }
+
+    /// The keypad that is actually on the door.
+    ///
+    /// ```text
+    ///     1
+    ///   2 3 4
+    /// 5 6 7 8 9
+    ///   A B C
+    ///     D
+    /// ```
+    fn actual() -> Self {
+        Self {
+            layout: &[
+                &[None, None, Some('1'), None, None],
+                &[None, Some('2'), Some('3'), Some('4'), None],
+                &[Some('5'), Some('6'), Some('7'), Some('8'), Some('9')],
+                &[None, Some('A'), Some('B'), Some('C'), None],
+                &[None, None, Some('D'), None, None],
+            ],
+            start: Position { row: 2, column: 0 },
+        }
+    }
+
+    fn button(&self, position: Position) -> Option<char> {
+        self.layout
+            .get(position.row)
+            .and_then(|row| row.get(position.column))
+            .copied()
+            .flatten()
+    }
+
+    /// Moves the finger, ignoring moves that don't lead to a button.
+    fn move_finger(&self, position: Position, step: Move) -> Position {
+        position
+            .step(step)
+            .filter(|&next| self.button(next).is_some())
+            .unwrap_or(position)
+    }
+
+    /// Follows each line of instructions and presses the button it ends on.
+    fn bathroom_code(&self, instructions: &Instructions) -> String {
+        let mut position = self.start;
+
+        instructions
+            .iter()
+            .map(|line| {
+                position = line
+                    .iter()
+                    .fold(position, |position, &step| self.move_finger(position, step));
+                self.button(position)
+                    .expect("finger should always be on a button")
+            })
+            .collect()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! moves {
+        ($($variant:ident),* $(,)?) => {
+            vec![$(Move::$variant),*]
+        };
+    }
+
+    struct TestData {
+        input: Input,
+        parsed: Instructions,
+        part1: String,
+        part2: String,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // AoC example:
+            TestData {
+                input: Input::from(vec!["ULL", "RRDDD", "LURDL", "UUUUD"]),
+                parsed: Instructions(vec![
+                    moves!(Up, Left, Left),
+                    moves!(Right, Right, Down, Down, Down),
+                    moves!(Left, Up, Right, Down, Left),
+                    moves!(Up, Up, Up, Up, Down),
+                ]),
+                part1: "1985".to_string(),
+                part2: "5DB3".to_string(),
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from(vec!["", "RRRRRR", "DDDDDD"]), // blank line ignored
+                parsed: Instructions(vec![
+                    moves!(Right, Right, Right, Right, Right, Right),
+                    moves!(Down, Down, Down, Down, Down, Down),
+                ]),
+                part1: "69".to_string(),
+                part2: "99".to_string(),
+            },
+            TestData {
+                input: Input::from(vec!["U?x", "L"]), // unknown chars ignored
+                parsed: Instructions(vec![moves!(Up), moves!(Left)]),
+                part1: "21".to_string(),
+                part2: "55".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(&data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        for data in get_test_data() {
+            assert_eq!(solve_part2(&data.parsed), data.part2);
+        }
+    }
+
+    #[test]
+    fn test_moves_off_the_keypad_are_ignored() {
+        let keypad = Keypad::actual();
+        let top = Position { row: 0, column: 2 };
+
+        assert_eq!(keypad.move_finger(top, Move::Up), top);
+        assert_eq!(keypad.move_finger(top, Move::Left), top);
+        assert_eq!(keypad.move_finger(top, Move::Right), top);
+        assert_eq!(
+            keypad.move_finger(top, Move::Down),
+            Position { row: 1, column: 2 }
+        );
+    }
+}
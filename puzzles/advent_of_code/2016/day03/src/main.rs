@@ -0,0 +1,187 @@
+use input_reader::{Input, Outcome, read_input};
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let rows = parse_input(input).collect::<Vec<_>>();
+
+    let possible_by_rows = solve_part1(&rows);
+    println!("Part 1 solution: {possible_by_rows}");
+
+    let possible_by_columns = solve_part2(&rows);
+    println!("Part 2 solution: {possible_by_columns}");
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> impl Iterator<Item = [u32; 3]> {
+    input
+        .non_blank_lines()
+        .map(|line| line.expect("failed to read line"))
+        .map(|line| {
+            let numbers = line
+                .split_whitespace()
+                .map(|number| number.parse().expect("failed to parse side length"))
+                .collect::<Vec<_>>();
+
+            numbers
+                .try_into()
+                .expect("each line should have three side lengths")
+        })
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(rows: &[[u32; 3]]) -> usize {
+    rows.iter()
+        .copied()
+        .filter(|&sides| is_possible_triangle(sides))
+        .count()
+}
+
+fn solve_part2(rows: &[[u32; 3]]) -> usize {
+    read_by_columns(rows)
+        .filter(|&sides| is_possible_triangle(sides))
+        .count()
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// Checks whether the sides can form a triangle.
+///
+/// In a valid triangle, the sum of any two sides must be larger than the
+/// remaining side, which only needs checking for the two shortest sides.
+fn is_possible_triangle(mut sides: [u32; 3]) -> bool {
+    sides.sort_unstable();
+    let [a, b, c] = sides;
+
+    // A sum that doesn't fit is larger than any side.
+    a.checked_add(b).is_none_or(|sum| sum > c)
+}
+
+/// Reads the triangles vertically instead of horizontally.
+///
+/// Each group of three rows holds three triangles, one in each column. Rows
+/// left over at the end that don't make up a full group are ignored.
+fn read_by_columns(rows: &[[u32; 3]]) -> impl Iterator<Item = [u32; 3]> {
+    rows.chunks_exact(3).flat_map(|group| {
+        let [[a1, b1, c1], [a2, b2, c2], [a3, b3, c3]] = group else {
+            unreachable!("chunks_exact always yields three rows");
+        };
+
+        [[*a1, *a2, *a3], [*b1, *b2, *b3], [*c1, *c2, *c3]]
+    })
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestData {
+        input: Input,
+        parsed: Vec<[u32; 3]>,
+        part1: usize,
+        part2: usize,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // AoC examples:
+            TestData {
+                input: Input::from("5 10 25"),
+                parsed: vec![[5, 10, 25]],
+                part1: 0,
+                part2: 0, // not enough rows to read vertically
+            },
+            TestData {
+                input: Input::from(vec![
+                    "101 301 501",
+                    "102 302 502",
+                    "103 303 503",
+                    "201 401 601",
+                    "202 402 602",
+                    "203 403 603",
+                ]),
+                parsed: vec![
+                    [101, 301, 501],
+                    [102, 302, 502],
+                    [103, 303, 503],
+                    [201, 401, 601],
+                    [202, 402, 602],
+                    [203, 403, 603],
+                ],
+                part1: 3,
+                part2: 6,
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from(vec!["  3  4  5", "", "  5  3  4", "  1  1  2"]),
+                parsed: vec![[3, 4, 5], [5, 3, 4], [1, 1, 2]],
+                part1: 2,
+                part2: 1, // [3, 5, 1] and [4, 3, 1] are invalid
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input).collect::<Vec<_>>(), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(&data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        for data in get_test_data() {
+            assert_eq!(solve_part2(&data.parsed), data.part2);
+        }
+    }
+
+    #[test]
+    fn test_valid_triangles() {
+        assert!(is_possible_triangle([3, 4, 5]));
+        assert!(is_possible_triangle([5, 4, 3]));
+        assert!(is_possible_triangle([1, 1, 1]));
+        assert!(is_possible_triangle([u32::MAX, u32::MAX, u32::MAX]));
+    }
+
+    #[test]
+    fn test_invalid_triangles() {
+        assert!(!is_possible_triangle([5, 10, 25]));
+        assert!(!is_possible_triangle([25, 10, 5]));
+        assert!(!is_possible_triangle([1, 1, 2])); // degenerate
+        assert!(!is_possible_triangle([0, 0, 0]));
+    }
+
+    #[test]
+    fn test_read_by_columns() {
+        let rows = [[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]];
+        let columns = read_by_columns(&rows).collect::<Vec<_>>();
+        assert_eq!(columns, vec![[1, 4, 7], [2, 5, 8], [3, 6, 9]]);
+    }
+}
@@ -0,0 +1,271 @@
+use input_reader::{Input, Outcome, read_input};
+use md5::{Digest as _, Md5};
+use rayon::prelude::*;
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let door_id = parse_input(input);
+
+    let password = find_password_v1(&door_id);
+    println!("Part 1 solution: {password}");
+
+    let password = find_password_v2(&door_id);
+    println!("Part 2 solution: {password}");
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> String {
+    input
+        .lines()
+        .map(|line| line.expect("failed to read line"))
+        .map(|line| line.trim().to_string())
+        .find(|line| !line.is_empty())
+        .expect("input should contain a door ID")
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn find_password_v1(door_id: &str) -> String {
+    password_v1(interesting_hashes(door_id))
+}
+
+fn find_password_v2(door_id: &str) -> String {
+    password_v2(interesting_hashes(door_id))
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// How many characters a password has.
+const PASSWORD_LENGTH: usize = 8;
+
+/// How many indices are hashed in parallel before checking the results.
+const BATCH_SIZE: u64 = 100_000;
+
+/// An MD5 hash.
+type Hash = [u8; 16];
+
+/// Hashes the door ID with increasing indices, yielding the interesting hashes in order.
+///
+/// Hashing is done in parallel batches, so a few more indices than needed may be hashed
+/// before the caller stops asking for more.
+fn interesting_hashes(door_id: &str) -> impl Iterator<Item = Hash> {
+    let prefix = Md5::new_with_prefix(door_id);
+
+    (0..).flat_map(move |batch: u64| {
+        let start = batch.saturating_mul(BATCH_SIZE);
+
+        (start..start.saturating_add(BATCH_SIZE))
+            .into_par_iter()
+            .map(|index| hash_index(&prefix, index))
+            .filter(is_interesting)
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Hashes the door ID, already fed to `prefix`, followed by `index`.
+fn hash_index(prefix: &Md5, index: u64) -> Hash {
+    let mut hasher = prefix.clone();
+    hasher.update(index.to_string());
+    hasher.finalize().into()
+}
+
+/// Checks whether the hexadecimal representation of the hash starts with five zeroes.
+fn is_interesting(hash: &Hash) -> bool {
+    matches!(hash, [0, 0, third, ..] if *third < 0x10)
+}
+
+/// The sixth hexadecimal digit of the hash.
+fn sixth_digit(hash: &Hash) -> u8 {
+    let [_, _, third, ..] = hash;
+    third & 0x0f
+}
+
+/// The seventh hexadecimal digit of the hash.
+fn seventh_digit(hash: &Hash) -> u8 {
+    let [_, _, _, fourth, ..] = hash;
+    fourth >> 4
+}
+
+/// Converts a hexadecimal digit to its lowercase character.
+fn hex_char(digit: u8) -> char {
+    char::from_digit(u32::from(digit), 16).expect("digit should be hexadecimal")
+}
+
+/// The first door's password is the sixth digit of each interesting hash.
+fn password_v1(hashes: impl IntoIterator<Item = Hash>) -> String {
+    hashes
+        .into_iter()
+        .take(PASSWORD_LENGTH)
+        .map(|hash| hex_char(sixth_digit(&hash)))
+        .collect()
+}
+
+/// The second door's password is filled in out of order.
+///
+/// The sixth digit of each interesting hash is a position and the seventh the
+/// character to put there. Invalid positions, and positions already filled, are
+/// skipped.
+fn password_v2(hashes: impl IntoIterator<Item = Hash>) -> String {
+    let mut password = [None; PASSWORD_LENGTH];
+
+    for hash in hashes {
+        if let Some(slot @ None) = password.get_mut(usize::from(sixth_digit(&hash))) {
+            *slot = Some(hex_char(seventh_digit(&hash)));
+
+            if password.iter().all(Option::is_some) {
+                break;
+            }
+        }
+    }
+
+    password
+        .into_iter()
+        .map(|c| c.expect("the hashes should fill in every position"))
+        .collect()
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestData {
+        input: Input,
+        parsed: String,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        vec![
+            // AoC example:
+            TestData {
+                input: Input::from("abc"),
+                parsed: "abc".to_string(),
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from(vec!["", "  ugkcyxxp  "]),
+                parsed: "ugkcyxxp".to_string(),
+            },
+        ]
+    }
+
+    /// The interesting hashes for the door ID `abc`, up to the last one the second
+    /// door needs.
+    ///
+    /// Hashing all of them takes too long for a test, so they were precomputed.
+    const EXAMPLE_HASHES: [(u64, &str); 16] = [
+        (3_231_929, "00000155f8105dff7f56ee10fa9b9abd"),
+        (5_017_308, "000008f82c5b3924a1ecbebf60344e00"),
+        (5_278_568, "00000f9a2c309875e05c5a5d09f1b8c4"),
+        (5_357_525, "000004e597bd77c5cd2133e9d885fe7e"),
+        (5_708_769, "0000073848c9ff7a27ca2e942ac10a4c"),
+        (6_082_117, "00000a9c311683dbbf122e9611a1c2d4"),
+        (8_036_669, "000003c75169d14fdb31ec1593915cff"),
+        (8_605_828, "0000000ea49fd3fc1b2f10e02d98ee96"),
+        (8_609_554, "000006e42e097c536b8be5179d65f327"),
+        (8_760_605, "000007b9278b049b172742aa82b5119a"),
+        (9_495_334, "000009d6e11733ceb6566b9c925a0770"),
+        (10_767_910, "00000cf8353c7d266a990865ea529f26"),
+        (11_039_607, "00000d64ba3bbc8102ec6179e495d88e"),
+        (12_763_908, "000006601753bceae1d061b3600deb3e"),
+        (13_666_005, "0000058939cbc6a1d1ab3bf7d29b0764"),
+        (13_753_421, "000002af5a2d97ef50063c37644d0166"),
+    ];
+
+    fn parse_hash(hex: &str) -> Hash {
+        let bytes = hex
+            .as_bytes()
+            .chunks_exact(2)
+            .map(|pair| {
+                let pair = str::from_utf8(pair).expect("hash should be ASCII");
+                u8::from_str_radix(pair, 16).expect("hash should be hexadecimal")
+            })
+            .collect::<Vec<_>>();
+
+        bytes.try_into().expect("hash should have 16 bytes")
+    }
+
+    fn example_hashes() -> Vec<Hash> {
+        EXAMPLE_HASHES
+            .iter()
+            .map(|(_, hex)| parse_hash(hex))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_password_v1() {
+        assert_eq!(password_v1(example_hashes()), "18f47a30");
+    }
+
+    #[test]
+    fn test_password_v2() {
+        assert_eq!(password_v2(example_hashes()), "05ace8e3");
+    }
+
+    #[test]
+    fn test_hash_index() {
+        let prefix = Md5::new_with_prefix("abc");
+
+        for (index, hex) in EXAMPLE_HASHES.iter().take(3) {
+            assert_eq!(hash_index(&prefix, *index), parse_hash(hex));
+        }
+    }
+
+    #[test]
+    fn test_is_interesting() {
+        let prefix = Md5::new_with_prefix("abc");
+
+        assert!(is_interesting(&hash_index(&prefix, 3_231_929)));
+        assert!(!is_interesting(&hash_index(&prefix, 3_231_928)));
+        assert!(is_interesting(&parse_hash(
+            "00000fffffffffffffffffffffffffff"
+        )));
+        assert!(!is_interesting(&parse_hash(
+            "000010ffffffffffffffffffffffffff"
+        )));
+    }
+
+    #[test]
+    fn test_password_v2_skips_invalid_and_filled_positions() {
+        let hashes = [
+            "0000081fffffffffffffffffffffffff", // position 8 is invalid
+            "0000001fffffffffffffffffffffffff",
+            "0000002fffffffffffffffffffffffff", // position 0 is already filled
+            "0000012fffffffffffffffffffffffff",
+            "0000023fffffffffffffffffffffffff",
+            "0000034fffffffffffffffffffffffff",
+            "0000045fffffffffffffffffffffffff",
+            "0000056fffffffffffffffffffffffff",
+            "0000067fffffffffffffffffffffffff",
+            "0000078fffffffffffffffffffffffff",
+        ];
+
+        assert_eq!(password_v2(hashes.map(parse_hash)), "12345678");
+    }
+}
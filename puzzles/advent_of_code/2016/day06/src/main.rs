@@ -0,0 +1,184 @@
+use input_reader::{Input, Outcome, read_input};
+use std::{cmp::Reverse, collections::HashMap};
+
+fn main() {
+    let input = match read_input().expect("failed to read input") {
+        Outcome::Exit => return,
+        Outcome::Continue(input) => input,
+        Outcome::DryRun(input) => {
+            let lines = input.lines().count();
+            println!("Dry run: input loaded successfully, {lines} lines");
+            return;
+        }
+    };
+
+    let lines = parse_input(input);
+
+    let message = solve_part1(&lines);
+    println!("Part 1 solution: {message}");
+
+    let message = solve_part2(&lines);
+    println!("Part 2 solution: {message}");
+}
+
+// -----------------------------------------------------------------------------
+// Input Parsing
+// -----------------------------------------------------------------------------
+
+fn parse_input(input: Input) -> Vec<String> {
+    input.trimmed_lines().expect("failed to read input")
+}
+
+// -----------------------------------------------------------------------------
+// Solutions
+// -----------------------------------------------------------------------------
+
+fn solve_part1(lines: &[String]) -> String {
+    decode_most_common(lines)
+}
+
+fn solve_part2(lines: &[String]) -> String {
+    decode_least_common(lines)
+}
+
+// -----------------------------------------------------------------------------
+// Internals
+// -----------------------------------------------------------------------------
+
+/// Decodes the message using the most frequent character of each column.
+///
+/// Ties are broken by picking the character that comes first alphabetically.
+fn decode_most_common(lines: &[String]) -> String {
+    decode(lines, |frequencies| {
+        frequencies
+            .iter()
+            .max_by_key(|&(&c, &count)| (count, Reverse(c)))
+            .map(|(&c, _)| c)
+    })
+}
+
+/// Decodes the message using the least frequent character of each column.
+///
+/// Ties are broken by picking the character that comes first alphabetically.
+fn decode_least_common(lines: &[String]) -> String {
+    decode(lines, |frequencies| {
+        frequencies
+            .iter()
+            .min_by_key(|&(&c, &count)| (count, c))
+            .map(|(&c, _)| c)
+    })
+}
+
+/// Decodes the message by picking one character from each column's frequencies.
+///
+/// The message is as long as the longest line, lines that are too short don't
+/// count towards the later columns.
+fn decode(lines: &[String], pick: impl Fn(&HashMap<char, usize>) -> Option<char>) -> String {
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    (0..width)
+        .filter_map(|column| pick(&column_frequencies(lines, column)))
+        .collect()
+}
+
+/// Counts how often each character appears in the column.
+fn column_frequencies(lines: &[String], column: usize) -> HashMap<char, usize> {
+    let mut frequencies = HashMap::new();
+
+    for c in lines.iter().filter_map(|line| line.chars().nth(column)) {
+        let count = frequencies.entry(c).or_insert(0_usize);
+        *count = count.saturating_add(1);
+    }
+
+    frequencies
+}
+
+// -----------------------------------------------------------------------------
+// Tests
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestData {
+        input: Input,
+        parsed: Vec<String>,
+        part1: &'static str,
+        part2: &'static str,
+    }
+
+    fn get_test_data() -> Vec<TestData> {
+        let example = vec![
+            "eedadn", "drvtee", "eandsr", "raavrd", "atevrs", "tsrnev", "sdttsa", "rasrtv",
+            "nssdts", "ntnada", "svetve", "tesnvt", "vntsnd", "vrdear", "dvrsen", "enarar",
+        ];
+
+        vec![
+            // AoC example:
+            TestData {
+                input: Input::from(example.clone()),
+                parsed: example.into_iter().map(String::from).collect(),
+                part1: "easter",
+                part2: "advent",
+            },
+            // Edge cases:
+            TestData {
+                input: Input::from(vec!["", "  ab  ", "ba", ""]),
+                parsed: vec!["ab".to_string(), "ba".to_string()],
+                part1: "aa", // ties pick the first character alphabetically
+                part2: "aa",
+            },
+            TestData {
+                input: Input::from(vec!["abc", "ab", "xb"]),
+                parsed: vec!["abc".to_string(), "ab".to_string(), "xb".to_string()],
+                part1: "abc", // only one line reaches the last column
+                part2: "xbc",
+            },
+            TestData {
+                input: Input::from(vec![""]),
+                parsed: vec![],
+                part1: "",
+                part2: "",
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_input() {
+        for data in get_test_data() {
+            assert_eq!(parse_input(data.input), data.parsed);
+        }
+    }
+
+    #[test]
+    fn test_solve_part1() {
+        for data in get_test_data() {
+            assert_eq!(solve_part1(&data.parsed), data.part1);
+        }
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        for data in get_test_data() {
+            assert_eq!(solve_part2(&data.parsed), data.part2);
+        }
+    }
+
+    #[test]
+    fn test_column_frequencies() {
+        let lines = vec!["ab".to_string(), "ac".to_string(), "b".to_string()];
+
+        let first = column_frequencies(&lines, 0);
+        assert_eq!(first, HashMap::from([('a', 2), ('b', 1)]));
+
+        let second = column_frequencies(&lines, 1);
+        assert_eq!(second, HashMap::from([('b', 1), ('c', 1)]));
+
+        assert!(column_frequencies(&lines, 2).is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+//! Smoke tests over the whole workspace.
+//!
+//! Run them with `cargo test -p workspace-tests`.
+
+#![expect(clippy::unwrap_used, reason = "unwrap is okay in tests")]
+
+use cargo_metadata::{Metadata, MetadataCommand, Package};
+use std::{path::Path, process::Command};
+
+/// Where the puzzle crates live, relative to the workspace root.
+const PUZZLES_DIR: &str = "puzzles/advent_of_code";
+
+fn workspace_metadata() -> Metadata {
+    let manifest_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("Cargo.toml");
+
+    MetadataCommand::new()
+        .manifest_path(manifest_path)
+        .no_deps()
+        .exec()
+        .unwrap()
+}
+
+/// Whether `package` and its tests compile.
+///
+/// The tests are only built, not run, so puzzle tests that would need an actual
+/// input file don't matter here.
+fn compiles_with_tests(metadata: &Metadata, package: &Package) -> bool {
+    Command::new(env!("CARGO"))
+        .args(["test", "--no-run", "--quiet", "--package"])
+        .arg(package.name.as_str())
+        .current_dir(&metadata.workspace_root)
+        .status()
+        .unwrap()
+        .success()
+}
+
+#[test]
+fn all_puzzle_crates_compile_with_tests() {
+    let metadata = workspace_metadata();
+    let puzzles_dir = metadata.workspace_root.join(PUZZLES_DIR);
+
+    let puzzles: Vec<_> = metadata
+        .workspace_packages()
+        .into_iter()
+        .filter(|package| package.manifest_path.starts_with(&puzzles_dir))
+        .collect();
+    assert!(!puzzles.is_empty(), "no puzzle crates found");
+
+    let failures: Vec<_> = puzzles
+        .into_iter()
+        .filter(|package| !compiles_with_tests(&metadata, package))
+        .map(|package| package.name.to_string())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "puzzle crates that don't compile: {failures:?}"
+    );
+}